@@ -0,0 +1,156 @@
+//! Measures the cost of escaping text content and attribute values.
+//!
+//! Each group compares the wrappers of the crate with the implementation they had
+//! before, scanning the input with [str::find] over an array of characters. The
+//! `buffer` group measures the text written through the [Buffer], where a [str] is
+//! escaped directly and any other value goes through an escaping writer.
+
+use std::fmt::Write;
+
+use another_html_builder::attribute::EscapedValue;
+use another_html_builder::content::EscapedContent;
+use another_html_builder::Buffer;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+/// Implementation of the wrappers scanning the input with [str::find].
+mod legacy {
+    const CONTENT_ESCAPE: [char; 6] = ['&', '<', '>', '"', '\'', '/'];
+    const VALUE_ESCAPE: [char; 4] = ['&', '"', '<', '>'];
+
+    fn escape(
+        f: &mut std::fmt::Formatter<'_>,
+        input: &str,
+        characters: &[char],
+    ) -> std::fmt::Result {
+        let mut start: usize = 0;
+        while let Some(index) = input[start..].find(characters) {
+            if index > 0 {
+                f.write_str(&input[start..(start + index)])?;
+            }
+            let begin = start + index;
+            let end = begin + 1;
+            match &input[begin..end] {
+                "&" => f.write_str("&amp;")?,
+                "<" => f.write_str("&lt;")?,
+                ">" => f.write_str("&gt;")?,
+                "\"" => f.write_str("&quot;")?,
+                "'" => f.write_str("&#x27;")?,
+                "/" => f.write_str("&#x2F;")?,
+                other => f.write_str(other)?,
+            };
+            start = end;
+        }
+        f.write_str(&input[start..])
+    }
+
+    pub struct EscapedContent<'a>(pub &'a str);
+
+    impl std::fmt::Display for EscapedContent<'_> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            escape(f, self.0, &CONTENT_ESCAPE)
+        }
+    }
+
+    pub struct EscapedValue<'a>(pub &'a str);
+
+    impl std::fmt::Display for EscapedValue<'_> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            escape(f, self.0, &VALUE_ESCAPE)
+        }
+    }
+}
+
+/// Inputs of 4kB, from plain prose to markup heavy text.
+fn inputs() -> Vec<(&'static str, String)> {
+    let repeat = |pattern: &str| pattern.repeat(4096 / pattern.len());
+    vec![
+        (
+            "plain",
+            repeat("Lorem ipsum dolor sit amet, consectetur adipiscing elit. "),
+        ),
+        (
+            "unicode",
+            repeat("Ça débute à l'été, où règne l'ambiance. "),
+        ),
+        (
+            "sparse",
+            repeat("Lorem ipsum dolor sit amet, \"consectetur\" adipiscing elit. "),
+        ),
+        ("dense", repeat("<a href=\"/b?c=d&e=f\">g</a>")),
+    ]
+}
+
+fn bench<L, N>(c: &mut Criterion, name: &str, legacy: L, new: N)
+where
+    L: Fn(&mut String, &str) -> std::fmt::Result,
+    N: Fn(&mut String, &str) -> std::fmt::Result,
+{
+    let mut group = c.benchmark_group(name);
+    for (kind, input) in inputs() {
+        group.throughput(Throughput::Bytes(input.len() as u64));
+        let mut output = String::with_capacity(input.len() * 6);
+        group.bench_with_input(BenchmarkId::new("legacy", kind), &input, |b, input| {
+            b.iter(|| {
+                output.clear();
+                legacy(&mut output, black_box(input)).unwrap();
+                black_box(output.len())
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("table", kind), &input, |b, input| {
+            b.iter(|| {
+                output.clear();
+                new(&mut output, black_box(input)).unwrap();
+                black_box(output.len())
+            })
+        });
+    }
+    group.finish();
+}
+
+fn content(c: &mut Criterion) {
+    bench(
+        c,
+        "content",
+        |output, input| write!(output, "{}", legacy::EscapedContent(input)),
+        |output, input| write!(output, "{}", EscapedContent(input)),
+    );
+}
+
+fn attribute(c: &mut Criterion) {
+    bench(
+        c,
+        "attribute",
+        |output, input| write!(output, "{}", legacy::EscapedValue(input)),
+        |output, input| write!(output, "{}", EscapedValue(input)),
+    );
+}
+
+fn buffer(c: &mut Criterion) {
+    let mut group = c.benchmark_group("buffer");
+    for (kind, input) in inputs() {
+        group.throughput(Throughput::Bytes(input.len() as u64));
+        let mut output = String::with_capacity(input.len() * 6);
+        group.bench_with_input(BenchmarkId::new("text", kind), &input, |b, input| {
+            b.iter(|| {
+                output.clear();
+                Buffer::from(&mut output).text(black_box(input));
+                black_box(output.len())
+            })
+        });
+        group.bench_with_input(
+            BenchmarkId::new("text_display", kind),
+            &input,
+            |b, input| {
+                b.iter(|| {
+                    output.clear();
+                    Buffer::from(&mut output).text_display(black_box(input));
+                    black_box(output.len())
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, content, attribute, buffer);
+criterion_main!(benches);
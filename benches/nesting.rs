@@ -0,0 +1,206 @@
+//! Measures the cost of nesting elements.
+//!
+//! The `stack` group compares the borrowed parents used by the [Buffer] with the boxed
+//! parents it used before, both writing the same raw output so only the layout of the
+//! element stack differs. The `buffer` group measures the [Buffer] on large documents.
+
+use another_html_builder::pretty::Pretty;
+use another_html_builder::{Body, Buffer};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+/// Layout of the element stack where each level boxes its parent.
+mod boxed {
+    pub enum Body<'a> {
+        Root,
+        Element {
+            name: &'a str,
+            parent: Box<Body<'a>>,
+        },
+    }
+
+    impl Body<'_> {
+        pub fn depth(&self) -> usize {
+            match self {
+                Self::Root => 0,
+                Self::Element { parent, .. } => parent.depth() + 1,
+            }
+        }
+    }
+
+    pub struct Buffer<'a> {
+        pub inner: String,
+        pub current: Body<'a>,
+    }
+
+    impl<'a> Buffer<'a> {
+        pub fn element<F>(mut self, name: &'a str, children: F) -> Self
+        where
+            F: FnOnce(Buffer<'a>) -> Buffer<'a>,
+        {
+            self.inner.push('<');
+            self.inner.push_str(name);
+            self.inner.push('>');
+            let buf = children(Buffer {
+                inner: self.inner,
+                current: Body::Element {
+                    name,
+                    parent: Box::new(self.current),
+                },
+            });
+            let (name, parent) = match buf.current {
+                Body::Element { name, parent } => (name, *parent),
+                Body::Root => unreachable!(),
+            };
+            let mut inner = buf.inner;
+            inner.push_str("</");
+            inner.push_str(name);
+            inner.push('>');
+            Buffer {
+                inner,
+                current: parent,
+            }
+        }
+    }
+}
+
+/// Layout of the element stack where each level borrows its parent from the call stack.
+mod borrowed {
+    #[derive(Clone, Copy)]
+    pub enum Body<'a> {
+        Root,
+        Element { name: &'a str, parent: &'a Body<'a> },
+    }
+
+    impl Body<'_> {
+        pub fn depth(&self) -> usize {
+            match self {
+                Self::Root => 0,
+                Self::Element { parent, .. } => parent.depth() + 1,
+            }
+        }
+    }
+
+    pub struct Buffer<'a> {
+        pub inner: String,
+        pub current: Body<'a>,
+    }
+
+    impl<'a> Buffer<'a> {
+        pub fn element<F>(mut self, name: &'a str, children: F) -> Self
+        where
+            F: for<'b> FnOnce(Buffer<'b>) -> Buffer<'b>,
+        {
+            self.inner.push('<');
+            self.inner.push_str(name);
+            self.inner.push('>');
+            let parent = self.current;
+            let buf = children(Buffer {
+                inner: self.inner,
+                current: Body::Element {
+                    name,
+                    parent: &parent,
+                },
+            });
+            let name = match buf.current {
+                Body::Element { name, .. } => name,
+                Body::Root => unreachable!(),
+            };
+            let mut inner = buf.inner;
+            inner.push_str("</");
+            inner.push_str(name);
+            inner.push('>');
+            Buffer {
+                inner,
+                current: parent,
+            }
+        }
+    }
+}
+
+fn boxed_deep(buf: boxed::Buffer<'_>, depth: usize) -> boxed::Buffer<'_> {
+    if depth == 0 {
+        black_box(buf.current.depth());
+        return buf;
+    }
+    buf.element("div", |buf| boxed_deep(buf, depth - 1))
+}
+
+fn borrowed_deep(buf: borrowed::Buffer<'_>, depth: usize) -> borrowed::Buffer<'_> {
+    if depth == 0 {
+        black_box(buf.current.depth());
+        return buf;
+    }
+    buf.element("div", |buf| borrowed_deep(buf, depth - 1))
+}
+
+fn stack(c: &mut Criterion) {
+    let mut group = c.benchmark_group("stack");
+    for depth in [10, 100, 1000] {
+        group.bench_with_input(BenchmarkId::new("boxed", depth), &depth, |b, depth| {
+            b.iter(|| {
+                let buf = boxed::Buffer {
+                    inner: String::with_capacity(depth * 12),
+                    current: boxed::Body::Root,
+                };
+                black_box(boxed_deep(buf, *depth).inner)
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("borrowed", depth), &depth, |b, depth| {
+            b.iter(|| {
+                let buf = borrowed::Buffer {
+                    inner: String::with_capacity(depth * 12),
+                    current: borrowed::Body::Root,
+                };
+                black_box(borrowed_deep(buf, *depth).inner)
+            })
+        });
+    }
+    group.finish();
+}
+
+fn deep<W: another_html_builder::prelude::WriterExt>(
+    buf: Buffer<W, Body<'_>>,
+    depth: usize,
+) -> Buffer<W, Body<'_>> {
+    if depth == 0 {
+        return buf.text("leaf");
+    }
+    buf.node("div").content(|buf| deep(buf, depth - 1))
+}
+
+fn buffer(c: &mut Criterion) {
+    let mut group = c.benchmark_group("buffer");
+    group.bench_function("table", |b| {
+        b.iter(|| {
+            Buffer::from(String::with_capacity(256 * 1024))
+                .node("table")
+                .content(|buf| {
+                    buf.node("tbody").content(|buf| {
+                        buf.iter(0..1000, |buf, _, row| {
+                            buf.node("tr").content(|buf| {
+                                buf.iter(0..10, |buf, _, cell| {
+                                    buf.node("td")
+                                        .attr(("data-cell", cell))
+                                        .content(|buf| buf.raw(row * cell))
+                                })
+                            })
+                        })
+                    })
+                })
+                .into_inner()
+        })
+    });
+    group.bench_function("deep", |b| {
+        b.iter(|| deep(Buffer::from(String::with_capacity(16 * 1024)), 500).into_inner())
+    });
+    group.bench_function("deep_pretty", |b| {
+        b.iter(|| {
+            let buf = Buffer::from(String::with_capacity(1024 * 1024)).pretty(Pretty::default());
+            deep(buf, 500).into_inner()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, stack, buffer);
+criterion_main!(benches);
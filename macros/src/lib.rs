@@ -0,0 +1,554 @@
+//! Procedural macros for [another-html-builder](https://docs.rs/another-html-builder).
+//!
+//! This crate shouldn't be used directly, enable the `macros` feature of
+//! `another-html-builder` instead.
+
+use proc_macro2::{Span, TokenStream, TokenTree};
+use quote::quote;
+use syn::ext::IdentExt;
+use syn::parse::{Parse, ParseStream};
+use syn::{braced, Expr, Ident, Lit, LitStr, Pat, Path, Token};
+
+/// Writes html with a JSX like syntax.
+///
+/// The macro expands to a closure taking a `Buffer` and returning it, chaining
+/// the same method calls that would be written by hand.
+/// See the documentation of `another_html_builder::html` for the syntax.
+#[proc_macro]
+pub fn html(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    match syn::parse::<Nodes>(input) {
+        Ok(nodes) => nodes.expand().into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+/// Name of an element or an attribute, like `div`, `data-id` or `xmlns:atom`.
+struct Name {
+    value: String,
+    span: Span,
+}
+
+impl Name {
+    fn to_lit(&self) -> LitStr {
+        LitStr::new(&self.value, self.span)
+    }
+}
+
+impl Parse for Name {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let first = Ident::parse_any(input)?;
+        let span = first.span();
+        let mut value = first.unraw().to_string();
+        loop {
+            if input.peek(Token![-]) {
+                input.parse::<Token![-]>()?;
+                value.push('-');
+            } else if input.peek(Token![:]) && !input.peek(Token![::]) {
+                input.parse::<Token![:]>()?;
+                value.push(':');
+            } else {
+                break;
+            }
+            if input.peek(syn::LitInt) {
+                value.push_str(&input.parse::<syn::LitInt>()?.to_string());
+            } else {
+                value.push_str(&Ident::parse_any(input)?.unraw().to_string());
+            }
+        }
+        Ok(Self { value, span })
+    }
+}
+
+/// Value of an attribute or of a component property.
+enum Value {
+    Lit(Lit),
+    Expr(Expr),
+}
+
+impl Parse for Value {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(syn::token::Brace) {
+            let content;
+            braced!(content in input);
+            Ok(Self::Expr(content.parse()?))
+        } else {
+            Ok(Self::Lit(input.parse()?))
+        }
+    }
+}
+
+impl quote::ToTokens for Value {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Self::Lit(inner) => inner.to_tokens(tokens),
+            Self::Expr(inner) => inner.to_tokens(tokens),
+        }
+    }
+}
+
+/// Attribute of an element.
+enum Attr {
+    /// `name`, `name="value"` or `name={value}`
+    Named { name: Name, value: Option<Value> },
+    /// `{attribute}`, any value accepted by `Buffer::attr`
+    Expr(Expr),
+}
+
+impl Parse for Attr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(syn::token::Brace) {
+            let content;
+            braced!(content in input);
+            return Ok(Self::Expr(content.parse()?));
+        }
+        let name = input.parse()?;
+        let value = if input.peek(Token![=]) {
+            input.parse::<Token![=]>()?;
+            Some(input.parse()?)
+        } else {
+            None
+        };
+        Ok(Self::Named { name, value })
+    }
+}
+
+/// Property of a component, `name="value"` or `name={value}`.
+struct Prop {
+    name: Ident,
+    value: Value,
+}
+
+impl Parse for Prop {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = Ident::parse_any(input)?;
+        input.parse::<Token![=]>()?;
+        let value = input.parse()?;
+        Ok(Self { name, value })
+    }
+}
+
+/// Alternative branch of a condition.
+enum Else {
+    If(Box<Node>),
+    Block(Nodes),
+}
+
+/// Arm of a `match`, the pattern includes the optional guard.
+struct Arm {
+    pattern: TokenStream,
+    body: Nodes,
+}
+
+enum Node {
+    /// `<name attr="value">children</name>` or `<name attr="value" />`
+    Element {
+        name: Name,
+        attrs: Vec<Attr>,
+        children: Option<Nodes>,
+    },
+    /// `<Component prop={value} />`
+    Component { path: Path, props: Vec<Prop> },
+    /// `"text"`
+    Text(LitStr),
+    /// `{expression}`
+    Expr(Expr),
+    /// `@{component}`
+    Render(Expr),
+    /// `if condition { children } else { children }`
+    If {
+        condition: TokenStream,
+        children: Nodes,
+        otherwise: Option<Else>,
+    },
+    /// `for pattern in iterator { children }`
+    For {
+        pattern: Pat,
+        iterator: Expr,
+        children: Nodes,
+    },
+    /// `match value { pattern => { children } }`
+    Match { value: Expr, arms: Vec<Arm> },
+}
+
+/// Parses the content of a braced group as a list of nodes.
+fn parse_braced_nodes(input: ParseStream) -> syn::Result<Nodes> {
+    let content;
+    braced!(content in input);
+    content.parse()
+}
+
+/// Checks if the next element starts with an uppercase identifier or is a path.
+fn peek_component(input: ParseStream) -> bool {
+    let fork = input.fork();
+    match fork.call(Ident::parse_any) {
+        Ok(ident) => {
+            fork.peek(Token![::])
+                || ident
+                    .to_string()
+                    .chars()
+                    .next()
+                    .is_some_and(|c| c.is_ascii_uppercase())
+        }
+        Err(_) => false,
+    }
+}
+
+impl Node {
+    fn parse_element(input: ParseStream) -> syn::Result<Self> {
+        let open = input.parse::<Token![<]>()?;
+        if peek_component(input) {
+            let path = input.call(Path::parse_mod_style)?;
+            let mut props = Vec::new();
+            while !input.peek(Token![/]) {
+                if input.is_empty() || input.peek(Token![>]) {
+                    return Err(syn::Error::new(
+                        open.span,
+                        "components cannot have children, they should be closed with `/>`",
+                    ));
+                }
+                props.push(input.parse()?);
+            }
+            input.parse::<Token![/]>()?;
+            input.parse::<Token![>]>()?;
+            return Ok(Self::Component { path, props });
+        }
+        let name: Name = input.parse()?;
+        if !name.value.starts_with(|c: char| c.is_ascii_alphabetic()) {
+            return Err(syn::Error::new(
+                name.span,
+                format!(
+                    "invalid tag name `{}`, it should start with an ascii letter",
+                    name.value
+                ),
+            ));
+        }
+        let mut attrs = Vec::new();
+        loop {
+            if input.peek(Token![/]) {
+                input.parse::<Token![/]>()?;
+                input.parse::<Token![>]>()?;
+                return Ok(Self::Element {
+                    name,
+                    attrs,
+                    children: None,
+                });
+            }
+            if input.peek(Token![>]) {
+                input.parse::<Token![>]>()?;
+                break;
+            }
+            if input.is_empty() {
+                return Err(syn::Error::new(
+                    name.span,
+                    format!("unclosed element `<{}>`", name.value),
+                ));
+            }
+            attrs.push(input.parse()?);
+        }
+        let children: Nodes = input.parse()?;
+        if input.is_empty() {
+            return Err(syn::Error::new(
+                name.span,
+                format!("unclosed element `<{}>`", name.value),
+            ));
+        }
+        input.parse::<Token![<]>()?;
+        input.parse::<Token![/]>()?;
+        let closing: Name = input.parse()?;
+        if closing.value != name.value {
+            return Err(syn::Error::new(
+                closing.span,
+                format!(
+                    "mismatched closing tag, expected `</{}>` but found `</{}>`",
+                    name.value, closing.value
+                ),
+            ));
+        }
+        input.parse::<Token![>]>()?;
+        Ok(Self::Element {
+            name,
+            attrs,
+            children: Some(children),
+        })
+    }
+
+    fn parse_if(input: ParseStream) -> syn::Result<Self> {
+        input.parse::<Token![if]>()?;
+        let mut condition = TokenStream::new();
+        while !input.peek(syn::token::Brace) {
+            if input.is_empty() {
+                return Err(input.error("expected `{` after the condition"));
+            }
+            condition.extend([input.parse::<TokenTree>()?]);
+        }
+        let children = parse_braced_nodes(input)?;
+        let otherwise = if input.peek(Token![else]) {
+            input.parse::<Token![else]>()?;
+            if input.peek(Token![if]) {
+                Some(Else::If(Box::new(Self::parse_if(input)?)))
+            } else {
+                Some(Else::Block(parse_braced_nodes(input)?))
+            }
+        } else {
+            None
+        };
+        Ok(Self::If {
+            condition,
+            children,
+            otherwise,
+        })
+    }
+
+    fn parse_for(input: ParseStream) -> syn::Result<Self> {
+        input.parse::<Token![for]>()?;
+        let pattern = input.call(Pat::parse_multi_with_leading_vert)?;
+        input.parse::<Token![in]>()?;
+        let iterator = input.call(Expr::parse_without_eager_brace)?;
+        let children = parse_braced_nodes(input)?;
+        Ok(Self::For {
+            pattern,
+            iterator,
+            children,
+        })
+    }
+
+    fn parse_match(input: ParseStream) -> syn::Result<Self> {
+        input.parse::<Token![match]>()?;
+        let value = input.call(Expr::parse_without_eager_brace)?;
+        let content;
+        braced!(content in input);
+        let mut arms = Vec::new();
+        while !content.is_empty() {
+            let mut pattern = TokenStream::new();
+            while !content.peek(Token![=>]) {
+                if content.is_empty() {
+                    return Err(content.error("expected `=>` after the pattern"));
+                }
+                pattern.extend([content.parse::<TokenTree>()?]);
+            }
+            content.parse::<Token![=>]>()?;
+            let body = parse_braced_nodes(&content)?;
+            if content.peek(Token![,]) {
+                content.parse::<Token![,]>()?;
+            }
+            arms.push(Arm { pattern, body });
+        }
+        Ok(Self::Match { value, arms })
+    }
+}
+
+impl Parse for Node {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(Token![<]) {
+            Self::parse_element(input)
+        } else if input.peek(LitStr) {
+            Ok(Self::Text(input.parse()?))
+        } else if input.peek(syn::token::Brace) {
+            let content;
+            braced!(content in input);
+            Ok(Self::Expr(content.parse()?))
+        } else if input.peek(Token![@]) {
+            input.parse::<Token![@]>()?;
+            let content;
+            braced!(content in input);
+            Ok(Self::Render(content.parse()?))
+        } else if input.peek(Token![if]) {
+            Self::parse_if(input)
+        } else if input.peek(Token![for]) {
+            Self::parse_for(input)
+        } else if input.peek(Token![match]) {
+            Self::parse_match(input)
+        } else {
+            Err(input.error(
+                "expected an element, a string literal, `{expression}`, `@{component}`, `if`, `for` or `match`",
+            ))
+        }
+    }
+}
+
+/// List of sibling nodes, until a closing tag or the end of the input.
+struct Nodes(Vec<Node>);
+
+impl Parse for Nodes {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut nodes = Vec::new();
+        while !input.is_empty() {
+            if input.peek(Token![<]) && input.peek2(Token![/]) {
+                break;
+            }
+            nodes.push(input.parse()?);
+        }
+        Ok(Self(nodes))
+    }
+}
+
+impl Nodes {
+    /// Identifier of the buffer in the generated code, hidden from the caller.
+    fn buffer() -> Ident {
+        Ident::new("buffer", Span::mixed_site())
+    }
+
+    fn expand(&self) -> TokenStream {
+        let buf = Self::buffer();
+        let block = self.expand_block();
+        quote! { |#buf| #block }
+    }
+
+    /// Generates a block writing the nodes and returning the buffer.
+    fn expand_block(&self) -> TokenStream {
+        let buf = Self::buffer();
+        let statements = self.0.iter().map(Node::expand);
+        quote! {
+            {
+                #(let #buf = #statements;)*
+                #buf
+            }
+        }
+    }
+}
+
+impl Node {
+    fn expand(&self) -> TokenStream {
+        let buf = Nodes::buffer();
+        match self {
+            Self::Element {
+                name,
+                attrs,
+                children,
+            } => {
+                let tag = name.to_lit();
+                let attrs = attrs.iter().map(|attr| match attr {
+                    Attr::Named { name, value: None } => {
+                        let name = name.to_lit();
+                        quote! { .attr(#name) }
+                    }
+                    Attr::Named {
+                        name,
+                        value: Some(value),
+                    } => {
+                        let name = name.to_lit();
+                        quote! { .attr((#name, #value)) }
+                    }
+                    Attr::Expr(expr) => quote! { .attr(#expr) },
+                });
+                let end = match children {
+                    Some(children) => {
+                        let block = children.expand_block();
+                        quote! { .content(|#buf| #block) }
+                    }
+                    None => quote! { .close() },
+                };
+                quote! { #buf.node(#tag) #(#attrs)* #end }
+            }
+            Self::Component { path, props } => {
+                let props = props
+                    .iter()
+                    .map(|Prop { name, value }| quote! { #name: #value });
+                quote! { #buf.render(&#path { #(#props),* }) }
+            }
+            Self::Text(text) => quote! { #buf.text(#text) },
+            Self::Expr(expr) => quote! { #buf.text_display(#expr) },
+            Self::Render(expr) => quote! { #buf.render(&(#expr)) },
+            Self::If {
+                condition,
+                children,
+                otherwise,
+            } => {
+                let children = children.expand_block();
+                let otherwise = match otherwise {
+                    Some(Else::If(node)) => node.expand(),
+                    Some(Else::Block(nodes)) => nodes.expand_block(),
+                    None => quote! { { #buf } },
+                };
+                quote! { if #condition #children else #otherwise }
+            }
+            Self::For {
+                pattern,
+                iterator,
+                children,
+            } => {
+                let children = children.expand_block();
+                quote! {
+                    {
+                        let mut #buf = #buf;
+                        for #pattern in #iterator {
+                            #buf = #children;
+                        }
+                        #buf
+                    }
+                }
+            }
+            Self::Match { value, arms } => {
+                let arms = arms.iter().map(|Arm { pattern, body }| {
+                    let body = body.expand_block();
+                    quote! { #pattern => #body }
+                });
+                quote! { match #value { #(#arms),* } }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::quote;
+
+    use super::Nodes;
+
+    fn parse_error(input: proc_macro2::TokenStream) -> String {
+        match syn::parse2::<Nodes>(input) {
+            Ok(_) => panic!("expected an error"),
+            Err(error) => error.to_string(),
+        }
+    }
+
+    #[test]
+    fn should_parse_complex_nodes() {
+        let nodes = syn::parse2::<Nodes>(quote! {
+            <div class="a" data-id={42} hidden {Some("here")}>
+                "text"
+                {value}
+                <br />
+                <Title text="hello" />
+                @{component}
+                if let Some(value) = value { <p>{value}</p> } else if other { "b" } else { "c" }
+                for (index, item) in items.iter().enumerate() { <li>{item}</li> }
+                match value { Some(_) if true => { "a" }, _ => {} }
+            </div>
+        })
+        .unwrap();
+        assert_eq!(nodes.0.len(), 1);
+    }
+
+    #[test]
+    fn should_fail_with_mismatched_closing_tag() {
+        assert_eq!(
+            parse_error(quote! { <div><p></div></p> }),
+            "mismatched closing tag, expected `</p>` but found `</div>`"
+        );
+    }
+
+    #[test]
+    fn should_fail_with_unclosed_element() {
+        assert_eq!(
+            parse_error(quote! { <div><p /> }),
+            "unclosed element `<div>`"
+        );
+    }
+
+    #[test]
+    fn should_fail_with_component_children() {
+        assert_eq!(
+            parse_error(quote! { <Title>"a"</Title> }),
+            "components cannot have children, they should be closed with `/>`"
+        );
+    }
+
+    #[test]
+    fn should_fail_with_invalid_tag_name() {
+        assert_eq!(
+            parse_error(quote! { <_div /> }),
+            "invalid tag name `_div`, it should start with an ascii letter"
+        );
+    }
+}
@@ -3,10 +3,48 @@
 
 use std::fmt::{Display, Write};
 
+mod class;
+mod style;
+mod url;
+
+pub use class::{ClassEntry, ClassList};
+pub use style::{CssString, CssUrl, Declaration, Declarations, InvalidProperty, Style, StyleValue};
+pub use url::{UrlValue, BLOCKED_URL, DEFAULT_SCHEMES};
+
+use crate::content::Escaping;
+use crate::dialect::Dialect;
+
 /// Wrapper around a [str] that will escape the content when writing.
+///
+/// This implementation will transform:
+/// - `&` to `&amp;`
+/// - `"` to `&quot;`
+/// - `<` to `&lt;`
+/// - `>` to `&gt;`
+///
+/// The produced value is safe to be written between double quotes.
 pub struct EscapedValue<'a>(pub &'a str);
 
 impl std::fmt::Display for EscapedValue<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        crate::escape::VALUE.write(f, self.0)
+    }
+}
+
+/// Wrapper around a [str] escaping double quotes with a backslash.
+///
+/// This was the behavior of [EscapedValue] before it started producing
+/// html character references. Html parsers don't understand `\"`, so a value
+/// written with this wrapper can close the attribute early and inject new ones.
+/// It's only kept to give some time to migrate code relying on the old output.
+#[deprecated(
+    since = "0.3.0",
+    note = "doesn't produce valid html, use `EscapedValue` instead"
+)]
+pub struct LegacyEscapedValue<'a>(pub &'a str);
+
+#[allow(deprecated)]
+impl std::fmt::Display for LegacyEscapedValue<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         if self.0.is_empty() {
             return Ok(());
@@ -39,17 +77,88 @@ macro_rules! attribute_value {
 /// Represents an element attribute name.
 pub trait AttributeName {
     fn render(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result;
+
+    /// Returns `false` when the name cannot be written as is, because it could end
+    /// the attribute early. The [Buffer](crate::Buffer) checks it before writing anything.
+    fn is_valid(&self) -> bool {
+        true
+    }
 }
 
+/// The name is written as is, and is valid when it follows
+/// [is_valid_attribute_name](crate::name::is_valid_attribute_name).
 impl AttributeName for &str {
     fn render(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.write_str(self)
     }
+
+    fn is_valid(&self) -> bool {
+        crate::name::is_valid_attribute_name(self)
+    }
+}
+
+macro_rules! attribute_string {
+    ($type:ty) => {
+        impl AttributeName for $type {
+            fn render(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                AttributeName::render(&self.as_ref(), f)
+            }
+
+            fn is_valid(&self) -> bool {
+                AttributeName::is_valid(&self.as_ref())
+            }
+        }
+
+        impl AttributeValue for $type {
+            fn render(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                EscapedValue(self).fmt(f)
+            }
+        }
+    };
+}
+
+/// Name of an xml namespace declaration attribute.
+///
+/// `Xmlns(None)` declares the default namespace while `Xmlns(Some(prefix))`
+/// declares a prefixed one.
+///
+/// ```rust
+/// use another_html_builder::attribute::Xmlns;
+/// use another_html_builder::dialect::Dialect;
+///
+/// let xml = another_html_builder::Buffer::default()
+///     .with_dialect(Dialect::Xml)
+///     .node("feed")
+///     .attr((Xmlns(None), "http://www.w3.org/2005/Atom"))
+///     .attr((Xmlns(Some("media")), "http://search.yahoo.com/mrss/"))
+///     .close()
+///     .into_inner();
+/// assert_eq!(
+///     xml,
+///     "<feed xmlns=\"http://www.w3.org/2005/Atom\" xmlns:media=\"http://search.yahoo.com/mrss/\" />"
+/// );
+/// ```
+pub struct Xmlns<'a>(pub Option<&'a str>);
+
+impl AttributeName for Xmlns<'_> {
+    fn render(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("xmlns")?;
+        if let Some(prefix) = self.0 {
+            f.write_char(':')?;
+            AttributeName::render(&prefix, f)?;
+        }
+        Ok(())
+    }
+
+    fn is_valid(&self) -> bool {
+        self.0.is_none_or(|prefix| AttributeName::is_valid(&prefix))
+    }
 }
 
 /// Represents an element attribute value.
 ///
-/// This value should be escaped for double quotes for example.
+/// This value will be written between double quotes, so it should at least escape
+/// `"` and `&` as html character references (see [EscapedValue]).
 /// The implementation of this trait on `&str` already implements this.
 pub trait AttributeValue {
     fn render(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result;
@@ -61,8 +170,86 @@ impl AttributeValue for &str {
     }
 }
 
+impl AttributeValue for char {
+    fn render(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        EscapedValue(self.encode_utf8(&mut [0; 4])).fmt(f)
+    }
+}
+
+macro_rules! attribute_float {
+    ($type:ty) => {
+        /// The infinite values and `NaN` are written like in javascript, as `Infinity`,
+        /// `-Infinity` and `NaN`, so they can be parsed with `Number` or `parseFloat`.
+        impl AttributeValue for $type {
+            fn render(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                if self.is_nan() {
+                    f.write_str("NaN")
+                } else if self.is_infinite() && self.is_sign_positive() {
+                    f.write_str("Infinity")
+                } else if self.is_infinite() {
+                    f.write_str("-Infinity")
+                } else {
+                    write!(f, "{self}")
+                }
+            }
+        }
+    };
+}
+
+/// Wrapper around a [Display] value that will escape it like [EscapedValue] when
+/// writing, without formatting it to a [String] first.
+///
+/// ```rust
+/// use another_html_builder::attribute::Escaped;
+///
+/// struct Point(i32, i32);
+///
+/// impl std::fmt::Display for Point {
+///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+///         write!(f, "<{}, {}>", self.0, self.1)
+///     }
+/// }
+///
+/// let html = another_html_builder::Buffer::default()
+///     .node("div")
+///     .attr(("data-point", Escaped(Point(1, -2))))
+///     .close()
+///     .into_inner();
+/// assert_eq!(html, "<div data-point=\"&lt;1, -2&gt;\"></div>");
+/// ```
+pub struct Escaped<T>(pub T);
+
+impl<T: Display> AttributeValue for Escaped<T> {
+    fn render(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(crate::escape::EscapingWriter::value(f), "{}", self.0)
+    }
+}
+
+/// Displays an attribute name, to report it when it's not valid.
+struct DisplayName<'a, N>(&'a N);
+
+impl<N: AttributeName> Display for DisplayName<'_, N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.render(f)
+    }
+}
+
+/// Displays an attribute value, to write it through an escaper.
+struct DisplayValue<'a, V>(&'a V);
+
+impl<V: AttributeValue> Display for DisplayValue<'_, V> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.render(f)
+    }
+}
+
 #[inline]
-fn render_attr_name_only<N: AttributeName>(
+fn invalid_name<N: AttributeName>(name: &N) -> Option<String> {
+    (!name.is_valid()).then(|| DisplayName(name).to_string())
+}
+
+#[inline]
+fn render_attr_name<N: AttributeName>(
     f: &mut std::fmt::Formatter<'_>,
     name: &N,
 ) -> std::fmt::Result {
@@ -70,24 +257,75 @@ fn render_attr_name_only<N: AttributeName>(
     name.render(f)
 }
 
+#[inline]
+fn render_attr_name_only<N: AttributeName>(
+    f: &mut std::fmt::Formatter<'_>,
+    name: &N,
+    dialect: Dialect,
+) -> std::fmt::Result {
+    render_attr_name(f, name)?;
+    if dialect.minimizes_attributes() {
+        return Ok(());
+    }
+    f.write_str("=\"")?;
+    name.render(f)?;
+    f.write_char('"')
+}
+
 #[inline]
 fn render_attr<N: AttributeName, V: AttributeValue>(
     f: &mut std::fmt::Formatter<'_>,
     name: &N,
     value: &V,
+    escaping: Option<Escaping>,
 ) -> std::fmt::Result {
-    render_attr_name_only(f, name)?;
+    render_attr_name(f, name)?;
     f.write_char('=')?;
     f.write_char('"')?;
-    value.render(f)?;
+    if escaping == Some(Escaping::AsciiOnly) {
+        crate::escape::Escaped(DisplayValue(value), &crate::escape::VALUE_NON_ASCII).fmt(f)?;
+    } else {
+        value.render(f)?;
+    }
     f.write_char('"')
 }
 
+/// Form of [Attribute] that can be written in an element.
+///
+/// This is implemented for every form accepted by [Buffer::attr](crate::Buffer::attr):
+/// a name, a name with a value, a name with a [bool], and those wrapped in an `Option`.
+pub trait RenderAttribute {
+    /// Writes the attribute, including the leading space, following the given [Dialect].
+    ///
+    /// With [Escaping::AsciiOnly], the non ascii characters of the value are written as
+    /// numeric character references, like `&#233;`. The other policies only apply to
+    /// the text content.
+    fn render(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        dialect: Dialect,
+        escaping: Option<Escaping>,
+    ) -> std::fmt::Result;
+
+    /// Returns the name of the attribute when it would be written and isn't valid,
+    /// see [AttributeName::is_valid].
+    fn invalid_name(&self) -> Option<String> {
+        None
+    }
+}
+
 /// Wrapper used for displaying attributes in elements
 ///
 /// This wrapper can print attributes with or without values.
 /// It can also handle attributes wrapped in an `Option` and will behave accordingly.
 ///
+/// It's displayed following the html5 serialization. Use [Attribute::with_dialect]
+/// to choose how attributes without values should be written: `selected` in html5 or
+/// `selected="selected"` in xhtml and xml.
+///
+/// The name is displayed as is, the [Buffer](crate::Buffer) rejects the invalid
+/// names before writing, see [RenderAttribute::invalid_name].
+///
 /// # Examples
 ///
 /// ```rust
@@ -101,7 +339,7 @@ fn render_attr<N: AttributeName, V: AttributeValue>(
 ///     .into_inner();
 /// assert_eq!(
 ///     html,
-///     "<div name-only name=\"value\" other=\"value\" with-number=\"42\" />"
+///     "<div name-only name=\"value\" other=\"value\" with-number=\"42\"></div>"
 /// );
 /// ```
 ///
@@ -132,62 +370,429 @@ fn render_attr<N: AttributeName, V: AttributeValue>(
 ///     .attr(("class", ClassNames(&["foo", "bar"])))
 ///     .close()
 ///     .into_inner();
-/// assert_eq!(html, "<div class=\"foo bar\" />");
+/// assert_eq!(html, "<div class=\"foo bar\"></div>");
 /// ```
 pub struct Attribute<T>(pub T);
 
-impl<N: AttributeName> std::fmt::Display for Attribute<Option<N>> {
+impl<T> Attribute<T> {
+    /// Displays the attribute following the given [Dialect].
+    ///
+    /// ```rust
+    /// use another_html_builder::attribute::Attribute;
+    /// use another_html_builder::dialect::Dialect;
+    ///
+    /// assert_eq!(Attribute("selected").to_string(), " selected");
+    /// assert_eq!(
+    ///     Attribute("selected")
+    ///         .with_dialect(Dialect::Xhtml)
+    ///         .to_string(),
+    ///     " selected=\"selected\""
+    /// );
+    /// ```
+    pub fn with_dialect(self, dialect: Dialect) -> DialectAttribute<T> {
+        DialectAttribute(self, dialect, None)
+    }
+}
+
+impl<T> std::fmt::Display for Attribute<T>
+where
+    Attribute<T>: RenderAttribute,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.render(f, Dialect::Html5, None)
+    }
+}
+
+/// [Attribute] displayed following a [Dialect], see [Attribute::with_dialect].
+pub struct DialectAttribute<T>(Attribute<T>, Dialect, Option<Escaping>);
+
+impl<T> DialectAttribute<T> {
+    /// Displays the attribute following the given [Escaping] policy, see
+    /// [RenderAttribute::render].
+    ///
+    /// ```rust
+    /// use another_html_builder::attribute::Attribute;
+    /// use another_html_builder::content::Escaping;
+    /// use another_html_builder::dialect::Dialect;
+    ///
+    /// assert_eq!(
+    ///     Attribute(("title", "café"))
+    ///         .with_dialect(Dialect::Html5)
+    ///         .with_escaping(Escaping::AsciiOnly)
+    ///         .to_string(),
+    ///     " title=\"caf&#233;\""
+    /// );
+    /// ```
+    pub fn with_escaping(mut self, escaping: Escaping) -> Self {
+        self.2 = Some(escaping);
+        self
+    }
+}
+
+impl<T> std::fmt::Display for DialectAttribute<T>
+where
+    Attribute<T>: RenderAttribute,
+{
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.render(f, self.1, self.2)
+    }
+}
+
+impl<N: AttributeName> RenderAttribute for Attribute<Option<N>> {
+    fn render(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        dialect: Dialect,
+        _escaping: Option<Escaping>,
+    ) -> std::fmt::Result {
         if let Some(ref inner) = self.0 {
-            render_attr_name_only(f, inner)
+            render_attr_name_only(f, inner, dialect)
         } else {
             Ok(())
         }
     }
+
+    fn invalid_name(&self) -> Option<String> {
+        self.0.as_ref().and_then(invalid_name)
+    }
 }
 
-impl<N: AttributeName> std::fmt::Display for Attribute<N> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        render_attr_name_only(f, &self.0)
+impl<N: AttributeName> RenderAttribute for Attribute<N> {
+    fn render(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        dialect: Dialect,
+        _escaping: Option<Escaping>,
+    ) -> std::fmt::Result {
+        render_attr_name_only(f, &self.0, dialect)
+    }
+
+    fn invalid_name(&self) -> Option<String> {
+        invalid_name(&self.0)
     }
 }
 
-impl<N: AttributeName, V: AttributeValue> std::fmt::Display for Attribute<Option<(N, V)>> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl<N: AttributeName, V: AttributeValue> RenderAttribute for Attribute<Option<(N, V)>> {
+    fn render(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        _dialect: Dialect,
+        escaping: Option<Escaping>,
+    ) -> std::fmt::Result {
         if let Some((name, value)) = &self.0 {
-            render_attr(f, name, value)
+            render_attr(f, name, value, escaping)
         } else {
             Ok(())
         }
     }
+
+    fn invalid_name(&self) -> Option<String> {
+        self.0.as_ref().and_then(|(name, _)| invalid_name(name))
+    }
 }
 
-impl<N: AttributeName, V: AttributeValue> std::fmt::Display for Attribute<(N, V)> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl<N: AttributeName, V: AttributeValue> RenderAttribute for Attribute<(N, V)> {
+    fn render(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        _dialect: Dialect,
+        escaping: Option<Escaping>,
+    ) -> std::fmt::Result {
         let (name, value) = &self.0;
-        render_attr(f, name, value)
+        render_attr(f, name, value, escaping)
+    }
+
+    fn invalid_name(&self) -> Option<String> {
+        invalid_name(&self.0 .0)
     }
 }
 
-attribute_value!(bool);
+/// Boolean attribute, written with only its name when `true` and not written when `false`.
+///
+/// Html considers a boolean attribute as set as soon as it's present, whatever its
+/// value, so `disabled="false"` disables the element. The enumerated attributes expecting
+/// `"true"` or `"false"`, like `aria-hidden` or `contenteditable`, should use [TrueFalse].
+///
+/// ```rust
+/// use another_html_builder::attribute::TrueFalse;
+///
+/// let html = another_html_builder::Buffer::default()
+///     .node("input")
+///     .attr(("disabled", true))
+///     .attr(("checked", false))
+///     .attr(("aria-hidden", TrueFalse(false)))
+///     .close()
+///     .into_inner();
+/// assert_eq!(html, "<input disabled aria-hidden=\"false\">");
+/// ```
+impl<N: AttributeName> RenderAttribute for Attribute<(N, bool)> {
+    fn render(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        dialect: Dialect,
+        _escaping: Option<Escaping>,
+    ) -> std::fmt::Result {
+        match &self.0 {
+            (name, true) => render_attr_name_only(f, name, dialect),
+            (_, false) => Ok(()),
+        }
+    }
+
+    fn invalid_name(&self) -> Option<String> {
+        match &self.0 {
+            (name, true) => invalid_name(name),
+            (_, false) => None,
+        }
+    }
+}
+
+impl<N: AttributeName> RenderAttribute for Attribute<Option<(N, bool)>> {
+    fn render(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        dialect: Dialect,
+        _escaping: Option<Escaping>,
+    ) -> std::fmt::Result {
+        match &self.0 {
+            Some((name, true)) => render_attr_name_only(f, name, dialect),
+            Some((_, false)) | None => Ok(()),
+        }
+    }
+
+    fn invalid_name(&self) -> Option<String> {
+        match &self.0 {
+            Some((name, true)) => invalid_name(name),
+            Some((_, false)) | None => None,
+        }
+    }
+}
+
+/// Boolean value written as `true` or `false`, for the enumerated attributes like
+/// `aria-hidden`, `contenteditable` or `draggable`.
+///
+/// A [bool] on its own is written as a boolean attribute, see [Attribute].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TrueFalse(pub bool);
+
+impl AttributeValue for TrueFalse {
+    fn render(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(if self.0 { "true" } else { "false" })
+    }
+}
+
+attribute_string!(String);
+attribute_string!(std::borrow::Cow<'_, str>);
+attribute_string!(Box<str>);
+attribute_string!(std::rc::Rc<str>);
+attribute_string!(std::sync::Arc<str>);
+
+attribute_float!(f32);
+attribute_float!(f64);
+
 attribute_value!(u8);
 attribute_value!(u16);
 attribute_value!(u32);
 attribute_value!(u64);
+attribute_value!(u128);
 attribute_value!(usize);
 attribute_value!(i8);
 attribute_value!(i16);
 attribute_value!(i32);
 attribute_value!(i64);
+attribute_value!(i128);
 attribute_value!(isize);
+attribute_value!(std::num::NonZeroU8);
+attribute_value!(std::num::NonZeroU16);
+attribute_value!(std::num::NonZeroU32);
+attribute_value!(std::num::NonZeroU64);
+attribute_value!(std::num::NonZeroU128);
+attribute_value!(std::num::NonZeroUsize);
+attribute_value!(std::num::NonZeroI8);
+attribute_value!(std::num::NonZeroI16);
+attribute_value!(std::num::NonZeroI32);
+attribute_value!(std::num::NonZeroI64);
+attribute_value!(std::num::NonZeroI128);
+attribute_value!(std::num::NonZeroIsize);
 
 #[cfg(test)]
 mod tests {
+    use crate::dialect::Dialect;
+
     #[test_case::test_case("hello world", "hello world"; "without character to escape")]
-    #[test_case::test_case("a\"b", "a\\\"b"; "with special in the middle")]
-    #[test_case::test_case("\"a", "\\\"a"; "with special at the beginning")]
-    #[test_case::test_case("a\"", "a\\\""; "with special at the end")]
+    #[test_case::test_case("a\"b", "a&quot;b"; "with special in the middle")]
+    #[test_case::test_case("\"a", "&quot;a"; "with special at the beginning")]
+    #[test_case::test_case("a\"", "a&quot;"; "with special at the end")]
+    #[test_case::test_case("a&b", "a&amp;b"; "with ampersand")]
+    #[test_case::test_case("&quot;", "&amp;quot;"; "with existing reference")]
+    #[test_case::test_case("<a>", "&lt;a&gt;"; "with brackets")]
+    #[test_case::test_case("a\" onclick=\"b", "a&quot; onclick=&quot;b"; "with injection")]
     fn escaping_attribute(input: &str, expected: &str) {
         assert_eq!(format!("{}", super::EscapedValue(input)), expected);
+        assert_eq!(
+            format!("{}", super::Attribute(("a", super::Escaped(input)))),
+            format!(" a=\"{expected}\"")
+        );
+    }
+
+    #[test_case::test_case("hello world", "hello world"; "without character to escape")]
+    #[test_case::test_case("a\"b&c", "a\\\"b&c"; "with special in the middle")]
+    #[allow(deprecated)]
+    fn legacy_escaping_attribute(input: &str, expected: &str) {
+        assert_eq!(format!("{}", super::LegacyEscapedValue(input)), expected);
+    }
+
+    #[test_case::test_case(Dialect::Html5, " selected"; "html5")]
+    #[test_case::test_case(Dialect::Xhtml, " selected=\"selected\""; "xhtml")]
+    #[test_case::test_case(Dialect::Xml, " selected=\"selected\""; "xml")]
+    fn name_only_attribute(dialect: Dialect, expected: &str) {
+        assert_eq!(
+            format!("{}", super::Attribute("selected").with_dialect(dialect)),
+            expected
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                super::Attribute(Some("selected")).with_dialect(dialect)
+            ),
+            expected
+        );
+    }
+
+    #[test_case::test_case(1.5_f64, "1.5"; "with decimal")]
+    #[test_case::test_case(-0.0_f64, "-0"; "with negative zero")]
+    #[test_case::test_case(f64::NAN, "NaN"; "with nan")]
+    #[test_case::test_case(f64::INFINITY, "Infinity"; "with infinity")]
+    #[test_case::test_case(f64::NEG_INFINITY, "-Infinity"; "with negative infinity")]
+    fn float_attribute(value: f64, expected: &str) {
+        assert_eq!(
+            format!("{}", super::Attribute(("a", value))),
+            format!(" a=\"{expected}\"")
+        );
+        assert_eq!(
+            format!("{}", super::Attribute(("a", value as f32))),
+            format!(" a=\"{expected}\"")
+        );
+    }
+
+    #[test]
+    fn owned_attribute() {
+        use std::borrow::Cow;
+        use std::rc::Rc;
+        use std::sync::Arc;
+
+        let name = String::from("data-name");
+        let expected = " data-name=\"a&quot;b\"";
+        let value = "a\"b";
+        assert_eq!(
+            format!("{}", super::Attribute((name.clone(), value.to_string()))),
+            expected
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                super::Attribute((
+                    Cow::Borrowed(name.as_str()),
+                    Cow::<str>::Owned(value.into())
+                ))
+            ),
+            expected
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                super::Attribute((Box::<str>::from(name.as_str()), Box::<str>::from(value)))
+            ),
+            expected
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                super::Attribute((Rc::<str>::from(name.as_str()), Rc::<str>::from(value)))
+            ),
+            expected
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                super::Attribute((Arc::<str>::from(name.as_str()), Arc::<str>::from(value)))
+            ),
+            expected
+        );
+        assert_eq!(format!("{}", super::Attribute(name)), " data-name");
+    }
+
+    #[test]
+    fn other_attribute_values() {
+        use std::num::{NonZeroI64, NonZeroU8};
+
+        assert_eq!(format!("{}", super::Attribute(("a", '"'))), " a=\"&quot;\"");
+        assert_eq!(
+            format!("{}", super::Attribute(("a", u128::MAX))),
+            " a=\"340282366920938463463374607431768211455\""
+        );
+        assert_eq!(
+            format!("{}", super::Attribute(("a", i128::MIN))),
+            " a=\"-170141183460469231731687303715884105728\""
+        );
+        assert_eq!(
+            format!("{}", super::Attribute(("a", NonZeroU8::MIN))),
+            " a=\"1\""
+        );
+        assert_eq!(
+            format!("{}", super::Attribute(("a", NonZeroI64::new(-3).unwrap()))),
+            " a=\"-3\""
+        );
+    }
+
+    #[test_case::test_case(Dialect::Html5, true, " disabled"; "html5 with true")]
+    #[test_case::test_case(Dialect::Xhtml, true, " disabled=\"disabled\""; "xhtml with true")]
+    #[test_case::test_case(Dialect::Html5, false, ""; "html5 with false")]
+    #[test_case::test_case(Dialect::Xml, false, ""; "xml with false")]
+    fn boolean_attribute(dialect: Dialect, value: bool, expected: &str) {
+        assert_eq!(
+            format!(
+                "{}",
+                super::Attribute(("disabled", value)).with_dialect(dialect)
+            ),
+            expected
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                super::Attribute(Some(("disabled", value))).with_dialect(dialect)
+            ),
+            expected
+        );
+    }
+
+    #[test_case::test_case(true, " aria-hidden=\"true\""; "with true")]
+    #[test_case::test_case(false, " aria-hidden=\"false\""; "with false")]
+    fn true_false_attribute(value: bool, expected: &str) {
+        assert_eq!(
+            format!(
+                "{}",
+                super::Attribute(("aria-hidden", super::TrueFalse(value)))
+            ),
+            expected
+        );
+    }
+
+    #[test_case::test_case("onclick=alert(1) a"; "with injection")]
+    #[test_case::test_case(""; "empty")]
+    fn invalid_attribute_name(name: &str) {
+        use super::RenderAttribute;
+
+        let expected = Some(name.to_string());
+        assert_eq!(super::Attribute((name, "a")).invalid_name(), expected);
+        assert_eq!(super::Attribute(Some(name)).invalid_name(), expected);
+        assert_eq!(super::Attribute((name, true)).invalid_name(), expected);
+        assert_eq!(super::Attribute(name.to_string()).invalid_name(), expected);
+        assert_eq!(
+            super::Attribute((super::Xmlns(Some(name)), "a")).invalid_name(),
+            Some(format!("xmlns:{name}"))
+        );
+        assert_eq!(super::Attribute((name, false)).invalid_name(), None);
+        assert_eq!(super::Attribute(None::<&str>).invalid_name(), None);
+        assert_eq!(super::Attribute(("a", name)).invalid_name(), None);
     }
 }
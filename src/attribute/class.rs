@@ -0,0 +1,132 @@
+use std::collections::HashSet;
+use std::fmt::{Display, Write};
+
+use super::{AttributeValue, EscapedValue};
+
+/// Represents an entry of a [ClassList].
+///
+/// An entry can provide a class name or nothing, in which case it's ignored.
+pub trait ClassEntry<'a> {
+    fn class_name(self) -> Option<&'a str>;
+}
+
+impl<'a> ClassEntry<'a> for &'a str {
+    fn class_name(self) -> Option<&'a str> {
+        Some(self)
+    }
+}
+
+/// Conditional entry, the class name is only used when the condition is `true`.
+impl<'a> ClassEntry<'a> for (&'a str, bool) {
+    fn class_name(self) -> Option<&'a str> {
+        self.1.then_some(self.0)
+    }
+}
+
+impl<'a, E: ClassEntry<'a>> ClassEntry<'a> for Option<E> {
+    fn class_name(self) -> Option<&'a str> {
+        self.and_then(ClassEntry::class_name)
+    }
+}
+
+/// List of class names, used as value of the `class` attribute.
+///
+/// The entries are written separated by a space, skipping the empty and duplicated
+/// class names, in the order they were added.
+///
+/// ```rust
+/// use another_html_builder::attribute::ClassList;
+///
+/// let active = true;
+/// let error: Option<&str> = None;
+/// let html = another_html_builder::Buffer::default()
+///     .node("button")
+///     .attr((
+///         "class",
+///         ClassList::new()
+///             .with("btn")
+///             .with(("btn-active", active))
+///             .with(error.map(|_| "btn-error"))
+///             .with_all(["btn", "large"]),
+///     ))
+///     .close()
+///     .into_inner();
+/// assert_eq!(html, "<button class=\"btn btn-active large\"></button>");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ClassList<'a> {
+    names: Vec<&'a str>,
+}
+
+impl<'a> ClassList<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends an entry to the list.
+    pub fn with<E: ClassEntry<'a>>(mut self, entry: E) -> Self {
+        self.names.extend(entry.class_name());
+        self
+    }
+
+    /// Appends all the entries to the list.
+    pub fn with_all<E, I>(mut self, entries: I) -> Self
+    where
+        E: ClassEntry<'a>,
+        I: IntoIterator<Item = E>,
+    {
+        self.extend(entries);
+        self
+    }
+}
+
+impl<'a, E: ClassEntry<'a>> Extend<E> for ClassList<'a> {
+    fn extend<I: IntoIterator<Item = E>>(&mut self, iter: I) {
+        self.names
+            .extend(iter.into_iter().filter_map(ClassEntry::class_name));
+    }
+}
+
+impl<'a, E: ClassEntry<'a>> FromIterator<E> for ClassList<'a> {
+    fn from_iter<I: IntoIterator<Item = E>>(iter: I) -> Self {
+        Self::new().with_all(iter)
+    }
+}
+
+impl AttributeValue for ClassList<'_> {
+    fn render(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut written = HashSet::with_capacity(self.names.len());
+        for name in self
+            .names
+            .iter()
+            .flat_map(|name| name.split_ascii_whitespace())
+        {
+            if !written.insert(name) {
+                continue;
+            }
+            if written.len() > 1 {
+                f.write_char(' ')?;
+            }
+            EscapedValue(name).fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ClassList;
+    use crate::attribute::Attribute;
+
+    #[test_case::test_case(ClassList::new(), ""; "without entries")]
+    #[test_case::test_case(ClassList::new().with("a").with(("b", false)).with(("c", true)), "a c"; "with conditions")]
+    #[test_case::test_case(ClassList::new().with(Some("a")).with(None::<&str>).with(Some(("b", false))), "a"; "with options")]
+    #[test_case::test_case(ClassList::new().with("a b").with("  ").with("b").with("a"), "a b"; "with duplicates")]
+    #[test_case::test_case(ClassList::from_iter(["a", "\"b\""]), "a &quot;b&quot;"; "with characters to escape")]
+    fn rendering_class_list(input: ClassList<'_>, expected: &str) {
+        assert_eq!(
+            Attribute(("class", input)).to_string(),
+            format!(" class=\"{expected}\"")
+        );
+    }
+}
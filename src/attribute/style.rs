@@ -0,0 +1,338 @@
+use std::fmt::Write;
+
+use super::AttributeValue;
+use crate::escape::EscapingWriter;
+
+/// Represents the value of a css declaration.
+///
+/// The rendered value doesn't need to be escaped for being written in an attribute,
+/// the [Style] takes care of it.
+pub trait StyleValue {
+    fn render(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result;
+
+    /// Returns `true` when the declaration should not be written.
+    fn is_skipped(&self) -> bool {
+        false
+    }
+}
+
+/// Raw css value, like `red` or `1px solid black`.
+///
+/// The characters `\`, `;`, `{`, `}` and the quotes are escaped, as well as the `/` starting
+/// a comment, so the value cannot open a string or a comment, or declare other properties.
+impl StyleValue for &str {
+    fn render(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut start: usize = 0;
+        let mut offset: usize = 0;
+        while let Some(index) = self[offset..].find(['\\', ';', '{', '}', '"', '\'', '/']) {
+            let begin = offset + index;
+            let end = begin + 1;
+            offset = end;
+            if self.as_bytes()[begin] == b'/' && self.as_bytes().get(end) != Some(&b'*') {
+                continue;
+            }
+            f.write_str(&self[start..begin])?;
+            f.write_char('\\')?;
+            f.write_str(&self[begin..end])?;
+            start = end;
+        }
+        f.write_str(&self[start..])
+    }
+}
+
+impl<V: StyleValue> StyleValue for Option<V> {
+    fn render(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Some(inner) => inner.render(f),
+            None => Ok(()),
+        }
+    }
+
+    fn is_skipped(&self) -> bool {
+        self.as_ref().is_none_or(StyleValue::is_skipped)
+    }
+}
+
+macro_rules! style_value {
+    ($type:ty) => {
+        impl StyleValue for $type {
+            fn render(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{self}")
+            }
+        }
+    };
+}
+
+style_value!(u8);
+style_value!(u16);
+style_value!(u32);
+style_value!(u64);
+style_value!(usize);
+style_value!(i8);
+style_value!(i16);
+style_value!(i32);
+style_value!(i64);
+style_value!(isize);
+style_value!(f32);
+style_value!(f64);
+
+/// Writes a css string token, with the surrounding double quotes.
+fn write_css_string(f: &mut std::fmt::Formatter<'_>, value: &str) -> std::fmt::Result {
+    f.write_char('"')?;
+    for c in value.chars() {
+        match c {
+            '"' | '\\' => {
+                f.write_char('\\')?;
+                f.write_char(c)?;
+            }
+            '\0' => f.write_str("\\FFFD ")?,
+            c if c.is_control() => write!(f, "\\{:X} ", c as u32)?,
+            c => f.write_char(c)?,
+        }
+    }
+    f.write_char('"')
+}
+
+/// Css string value, like in `content: "foo"` or `font-family: "Open Sans"`.
+///
+/// The quotes, backslashes and control characters are escaped.
+pub struct CssString<'a>(pub &'a str);
+
+impl StyleValue for CssString<'_> {
+    fn render(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_css_string(f, self.0)
+    }
+}
+
+/// Css url value, like in `background-image: url("image.png")`.
+///
+/// The url is written as an escaped css string.
+pub struct CssUrl<'a>(pub &'a str);
+
+impl StyleValue for CssUrl<'_> {
+    fn render(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("url(")?;
+        write_css_string(f, self.0)?;
+        f.write_char(')')
+    }
+}
+
+/// A single css declaration of a [Style].
+pub struct Declaration<'a, V> {
+    property: &'a str,
+    value: V,
+}
+
+/// List of css declarations of a [Style].
+///
+/// This is implemented by the declarations accumulated with [Style::with].
+pub trait Declarations {
+    /// Writes the declarations that are not skipped and returns how many have been written.
+    fn render(&self, f: &mut std::fmt::Formatter<'_>) -> Result<usize, std::fmt::Error>;
+}
+
+impl Declarations for () {
+    fn render(&self, _f: &mut std::fmt::Formatter<'_>) -> Result<usize, std::fmt::Error> {
+        Ok(0)
+    }
+}
+
+impl<D: Declarations, V: StyleValue> Declarations for (D, Declaration<'_, V>) {
+    fn render(&self, f: &mut std::fmt::Formatter<'_>) -> Result<usize, std::fmt::Error> {
+        let (previous, current) = self;
+        let count = previous.render(f)?;
+        if current.value.is_skipped() {
+            return Ok(count);
+        }
+        if count > 0 {
+            f.write_char(';')?;
+        }
+        f.write_str(current.property)?;
+        f.write_char(':')?;
+        current.value.render(f)?;
+        Ok(count + 1)
+    }
+}
+
+/// Inline css style, used as value of the `style` attribute.
+///
+/// The declarations are accumulated without allocating and written directly
+/// in the buffer. Declarations with a `None` value are skipped.
+///
+/// ```rust
+/// use another_html_builder::attribute::{CssString, CssUrl, Style};
+///
+/// let width: Option<u32> = None;
+/// let html = another_html_builder::Buffer::default()
+///     .node("div")
+///     .attr((
+///         "style",
+///         Style::new()
+///             .with("color", "red")
+///             .with("width", width)
+///             .with("font-family", CssString("Open \"Sans\""))
+///             .with("background", CssUrl("image.png")),
+///     ))
+///     .close()
+///     .into_inner();
+/// assert_eq!(
+///     html,
+///     "<div style=\"color:red;font-family:&quot;Open \\&quot;Sans\\&quot;&quot;;background:url(&quot;image.png&quot;)\"></div>"
+/// );
+/// ```
+pub struct Style<D = ()>(D);
+
+impl Style {
+    pub fn new() -> Self {
+        Self(())
+    }
+}
+
+impl Default for Style {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<D: Declarations> Style<D> {
+    /// Appends a declaration to the style.
+    ///
+    /// # Panics
+    ///
+    /// Panics when the property is not a valid css identifier,
+    /// see [is_valid_css_property](crate::name::is_valid_css_property). This is meant
+    /// for literal properties, use [Style::try_with] for the ones built at runtime.
+    pub fn with<V: StyleValue>(self, property: &str, value: V) -> Style<(D, Declaration<'_, V>)> {
+        match self.try_with(property, value) {
+            Ok(style) => style,
+            Err(error) => panic!("{error}"),
+        }
+    }
+
+    /// Appends a declaration to the style, returning an error when the property is not
+    /// a valid css identifier.
+    ///
+    /// ```rust
+    /// use another_html_builder::attribute::Style;
+    ///
+    /// let property = String::from("color:red;background");
+    /// let error = Style::new().try_with(&property, "blue").err().unwrap();
+    /// assert_eq!(error.property(), "color:red;background");
+    /// ```
+    pub fn try_with<V: StyleValue>(
+        self,
+        property: &str,
+        value: V,
+    ) -> Result<Style<(D, Declaration<'_, V>)>, InvalidProperty<'_>> {
+        if !crate::name::is_valid_css_property(property) {
+            return Err(InvalidProperty(property));
+        }
+        Ok(Style((self.0, Declaration { property, value })))
+    }
+}
+
+/// Error returned by [Style::try_with] when the property is not a valid css identifier.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InvalidProperty<'a>(&'a str);
+
+impl<'a> InvalidProperty<'a> {
+    /// Property that has been rejected.
+    pub fn property(&self) -> &'a str {
+        self.0
+    }
+}
+
+impl std::fmt::Display for InvalidProperty<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid css property {:?}", self.0)
+    }
+}
+
+impl std::error::Error for InvalidProperty<'_> {}
+
+/// Displays the declarations, to go through the [EscapingWriter].
+struct DisplayDeclarations<'a, D>(&'a D);
+
+impl<D: Declarations> std::fmt::Display for DisplayDeclarations<'_, D> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.render(f).map(|_| ())
+    }
+}
+
+impl<D: Declarations> AttributeValue for Style<D> {
+    fn render(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(EscapingWriter::value(f), "{}", DisplayDeclarations(&self.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CssString, CssUrl, Style, StyleValue};
+    use crate::attribute::Attribute;
+
+    struct DisplayValue<V>(V);
+
+    impl<V: StyleValue> std::fmt::Display for DisplayValue<V> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            self.0.render(f)
+        }
+    }
+
+    #[test_case::test_case("red", "red"; "without character to escape")]
+    #[test_case::test_case("red;background:blue", "red\\;background:blue"; "with semicolon")]
+    #[test_case::test_case("}a{", "\\}a\\{"; "with brackets")]
+    #[test_case::test_case("a\\;b:c", "a\\\\\\;b:c"; "with escaped semicolon")]
+    #[test_case::test_case("a/*", "a\\/*"; "with comment")]
+    #[test_case::test_case("1px/2px", "1px/2px"; "with slash")]
+    #[test_case::test_case("\"a;b:c", "\\\"a\\;b:c"; "with double quote")]
+    #[test_case::test_case("'a", "\\'a"; "with single quote")]
+    fn rendering_raw_value(input: &str, expected: &str) {
+        assert_eq!(DisplayValue(input).to_string(), expected);
+    }
+
+    #[test_case::test_case("Open Sans", "\"Open Sans\""; "without character to escape")]
+    #[test_case::test_case("a\"b\\c", "\"a\\\"b\\\\c\""; "with quote and backslash")]
+    #[test_case::test_case("a\nb\0", "\"a\\A b\\FFFD \""; "with control characters")]
+    fn rendering_string(input: &str, expected: &str) {
+        assert_eq!(DisplayValue(CssString(input)).to_string(), expected);
+    }
+
+    #[test]
+    fn rendering_url() {
+        assert_eq!(
+            DisplayValue(CssUrl("a\").png")).to_string(),
+            "url(\"a\\\").png\")"
+        );
+    }
+
+    #[test]
+    fn rendering_style() {
+        let style = Style::new()
+            .with("display", None::<&str>)
+            .with("margin", 0)
+            .with("opacity", Some(0.5))
+            .with("content", CssString("<\">"));
+        assert_eq!(
+            Attribute(("style", style)).to_string(),
+            " style=\"margin:0;opacity:0.5;content:&quot;&lt;\\&quot;&gt;&quot;\""
+        );
+    }
+
+    #[test]
+    fn rendering_fallible_style() {
+        let style = Style::new().try_with("color", "red").unwrap();
+        assert!(style.try_with("a b", "blue").is_err());
+    }
+
+    #[test]
+    #[should_panic = "invalid css property"]
+    fn rendering_invalid_property() {
+        let _ = Style::new().with("color:red;background", "blue");
+    }
+
+    #[test]
+    fn rendering_empty_style() {
+        let style = Style::new().with("display", None::<&str>);
+        assert_eq!(Attribute(("style", style)).to_string(), " style=\"\"");
+    }
+}
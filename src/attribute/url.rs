@@ -0,0 +1,150 @@
+use super::AttributeValue;
+
+/// Schemes allowed by default in a [UrlValue].
+pub const DEFAULT_SCHEMES: &[&str] = &["http", "https", "mailto", "tel"];
+
+/// Url written in place of a url using a scheme that is not allowed.
+pub const BLOCKED_URL: &str = "about:invalid#blocked";
+
+/// Checks if the character is removed from urls by browsers.
+fn is_tab_or_newline(c: char) -> bool {
+    matches!(c, '\t' | '\n' | '\r')
+}
+
+/// Checks if the character is removed from the beginning and the end of urls by browsers.
+fn is_c0_control_or_space(c: char) -> bool {
+    c <= ' '
+}
+
+/// Checks if the character should be percent encoded in an attribute value.
+fn should_percent_encode(c: char) -> bool {
+    !c.is_ascii() || c.is_ascii_control() || matches!(c, ' ' | '"' | '<' | '>' | '`')
+}
+
+/// Wrapper around a url used as attribute value, like in `href`, `src` or `action`.
+///
+/// The scheme of the url is checked against an allowlist ([DEFAULT_SCHEMES] by default)
+/// and the url is replaced by [BLOCKED_URL] when it's not allowed. Relative urls are
+/// always allowed. The scheme is detected the way browsers do, so `JavaScript:` or
+/// `java\tscript:` are not allowed either.
+///
+/// When allowed, the characters that are not valid in a url are percent encoded and
+/// the value is escaped for being written in an attribute.
+///
+/// ```rust
+/// use another_html_builder::attribute::UrlValue;
+///
+/// let html = another_html_builder::Buffer::default()
+///     .node("a")
+///     .attr(("href", UrlValue::new("https://example.com/a b?c=d&e=f")))
+///     .close()
+///     .node("a")
+///     .attr(("href", UrlValue::new(" javascript:alert(1)")))
+///     .close()
+///     .node("a")
+///     .attr((
+///         "href",
+///         UrlValue::new("ftp://example.com").with_schemes(&["ftp"]),
+///     ))
+///     .close()
+///     .into_inner();
+/// assert_eq!(
+///     html,
+///     "<a href=\"https://example.com/a%20b?c=d&amp;e=f\"></a><a href=\"about:invalid#blocked\"></a><a href=\"ftp://example.com\"></a>"
+/// );
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct UrlValue<'a> {
+    value: &'a str,
+    schemes: &'a [&'a str],
+}
+
+impl<'a> UrlValue<'a> {
+    pub fn new(value: &'a str) -> Self {
+        Self {
+            value: value.trim_matches(is_c0_control_or_space),
+            schemes: DEFAULT_SCHEMES,
+        }
+    }
+
+    /// Replaces the list of allowed schemes.
+    ///
+    /// The schemes are compared case insensitively.
+    pub fn with_schemes(mut self, schemes: &'a [&'a str]) -> Self {
+        self.schemes = schemes;
+        self
+    }
+
+    /// Returns the scheme of the url, with the tabs and newlines it might contain.
+    fn scheme(&self) -> Option<&'a str> {
+        for (index, c) in self.value.char_indices() {
+            match c {
+                ':' if index > 0 => return Some(&self.value[..index]),
+                c if c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.') => {}
+                c if index > 0 && is_tab_or_newline(c) => {}
+                _ => return None,
+            }
+        }
+        None
+    }
+
+    /// Checks if the url is relative or has an allowed scheme.
+    pub fn is_allowed(&self) -> bool {
+        let Some(scheme) = self.scheme() else {
+            return true;
+        };
+        self.schemes.iter().any(|allowed| {
+            scheme
+                .chars()
+                .filter(|c| !is_tab_or_newline(*c))
+                .map(|c| c.to_ascii_lowercase())
+                .eq(allowed.chars().map(|c| c.to_ascii_lowercase()))
+        })
+    }
+}
+
+impl AttributeValue for UrlValue<'_> {
+    fn render(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if !self.is_allowed() {
+            return f.write_str(BLOCKED_URL);
+        }
+        let mut bytes = [0u8; 4];
+        for c in self.value.chars() {
+            if is_tab_or_newline(c) {
+                continue;
+            } else if c == '&' {
+                f.write_str("&amp;")?;
+            } else if should_percent_encode(c) {
+                for byte in c.encode_utf8(&mut bytes).bytes() {
+                    write!(f, "%{byte:02X}")?;
+                }
+            } else {
+                std::fmt::Write::write_char(f, c)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UrlValue;
+    use crate::attribute::Attribute;
+
+    #[test_case::test_case("https://example.com/", "https://example.com/"; "with allowed scheme")]
+    #[test_case::test_case("HTTPS://example.com/", "HTTPS://example.com/"; "with uppercase scheme")]
+    #[test_case::test_case("/a/b?c", "/a/b?c"; "with relative url")]
+    #[test_case::test_case("a/b:c", "a/b:c"; "with colon in path")]
+    #[test_case::test_case("javascript:alert(1)", "about:invalid#blocked"; "with javascript")]
+    #[test_case::test_case(" JaVaScRiPt:alert(1)", "about:invalid#blocked"; "with mixed case javascript")]
+    #[test_case::test_case("java\tscript:alert(1)", "about:invalid#blocked"; "with tab in scheme")]
+    #[test_case::test_case("data:text/html,a", "about:invalid#blocked"; "with data")]
+    #[test_case::test_case("/é \"<x>\"", "/%C3%A9%20%22%3Cx%3E%22"; "with characters to encode")]
+    #[test_case::test_case("/?a=1&b=2", "/?a=1&amp;b=2"; "with ampersand")]
+    fn rendering_url(input: &str, expected: &str) {
+        assert_eq!(
+            format!("{}", Attribute(("href", UrlValue::new(input)))),
+            format!(" href=\"{expected}\"")
+        );
+    }
+}
@@ -0,0 +1,141 @@
+//! Attribute name related module. This contains a constant for every standard html attribute,
+//! including the ARIA attributes and the event handlers.
+//!
+//! The constants are validated at compile time and can be used with [Buffer::attr](crate::Buffer::attr)
+//! as any other attribute name, so a misspelled constant fails the compilation.
+//!
+//! They are only a convenience: [Buffer::attr](crate::Buffer::attr) keeps accepting any [str],
+//! so a typo in a string literal is still written as is, as long as it's a valid attribute name.
+//! Custom attributes, like the `data-*` ones, are written with a [str].
+//!
+//! ```rust
+//! use another_html_builder::attrs::{ARIA_LABEL, CLASS, HIDDEN, HREF};
+//!
+//! let html = another_html_builder::Buffer::default()
+//!     .node("a")
+//!     .attr((HREF, "/"))
+//!     .attr((CLASS, "link"))
+//!     .attr((ARIA_LABEL, "Home"))
+//!     .attr(HIDDEN)
+//!     .attr(("data-id", 42))
+//!     .close()
+//!     .into_inner();
+//! assert_eq!(
+//!     html,
+//!     "<a href=\"/\" class=\"link\" aria-label=\"Home\" hidden data-id=\"42\"></a>"
+//! );
+//! ```
+//!
+//! ```compile_fail
+//! use another_html_builder::attrs::CALSS;
+//! ```
+
+macro_rules! attributes {
+    ($kind:literal, $($constant:ident = $name:literal;)*) => {
+        $(
+            #[doc = concat!("The `", $name, "` ", $kind, ".")]
+            pub const $constant: &str = crate::name::attribute_name($name);
+        )*
+    };
+}
+
+attributes! {
+    "global attribute",
+    ACCESSKEY = "accesskey"; AUTOCAPITALIZE = "autocapitalize"; AUTOFOCUS = "autofocus";
+    CLASS = "class"; CONTENTEDITABLE = "contenteditable"; DIR = "dir"; DRAGGABLE = "draggable";
+    ENTERKEYHINT = "enterkeyhint"; HIDDEN = "hidden"; ID = "id"; INERT = "inert";
+    INPUTMODE = "inputmode"; IS = "is"; ITEMID = "itemid"; ITEMPROP = "itemprop";
+    ITEMREF = "itemref"; ITEMSCOPE = "itemscope"; ITEMTYPE = "itemtype"; LANG = "lang";
+    NONCE = "nonce"; POPOVER = "popover"; ROLE = "role"; SLOT = "slot";
+    SPELLCHECK = "spellcheck"; STYLE = "style"; TABINDEX = "tabindex"; TITLE = "title";
+    TRANSLATE = "translate"; WRITINGSUGGESTIONS = "writingsuggestions";
+}
+
+attributes! {
+    "attribute",
+    ABBR = "abbr"; ACCEPT = "accept"; ACCEPT_CHARSET = "accept-charset"; ACTION = "action";
+    ALLOW = "allow"; ALT = "alt"; AS = "as"; ASYNC = "async"; AUTOCOMPLETE = "autocomplete";
+    AUTOPLAY = "autoplay"; BLOCKING = "blocking"; CHARSET = "charset"; CHECKED = "checked";
+    CITE = "cite"; COLS = "cols"; COLSPAN = "colspan"; CONTENT = "content";
+    CONTROLS = "controls"; COORDS = "coords"; CROSSORIGIN = "crossorigin"; DATA = "data";
+    DATETIME = "datetime"; DECODING = "decoding"; DEFAULT = "default"; DEFER = "defer";
+    DIRNAME = "dirname"; DISABLED = "disabled"; DOWNLOAD = "download"; ENCTYPE = "enctype";
+    FETCHPRIORITY = "fetchpriority"; FOR = "for"; FORM = "form"; FORMACTION = "formaction";
+    FORMENCTYPE = "formenctype"; FORMMETHOD = "formmethod"; FORMNOVALIDATE = "formnovalidate";
+    FORMTARGET = "formtarget"; HEADERS = "headers"; HEIGHT = "height"; HIGH = "high";
+    HREF = "href"; HREFLANG = "hreflang"; HTTP_EQUIV = "http-equiv"; INTEGRITY = "integrity";
+    ISMAP = "ismap"; KIND = "kind"; LABEL = "label"; LIST = "list"; LOADING = "loading";
+    LOOP = "loop"; LOW = "low"; MAX = "max"; MAXLENGTH = "maxlength"; MEDIA = "media";
+    METHOD = "method"; MIN = "min"; MINLENGTH = "minlength"; MULTIPLE = "multiple";
+    MUTED = "muted"; NAME = "name"; NOMODULE = "nomodule"; NOVALIDATE = "novalidate";
+    OPEN = "open"; OPTIMUM = "optimum"; PATTERN = "pattern"; PING = "ping";
+    PLACEHOLDER = "placeholder"; PLAYSINLINE = "playsinline"; POPOVERTARGET = "popovertarget";
+    POPOVERTARGETACTION = "popovertargetaction"; POSTER = "poster"; PRELOAD = "preload";
+    READONLY = "readonly"; REFERRERPOLICY = "referrerpolicy"; REL = "rel";
+    REQUIRED = "required"; REVERSED = "reversed"; ROWS = "rows"; ROWSPAN = "rowspan";
+    SANDBOX = "sandbox"; SCOPE = "scope"; SELECTED = "selected"; SHAPE = "shape";
+    SIZE = "size"; SIZES = "sizes"; SPAN = "span"; SRC = "src"; SRCDOC = "srcdoc";
+    SRCLANG = "srclang"; SRCSET = "srcset"; START = "start"; STEP = "step"; TARGET = "target";
+    TYPE = "type"; USEMAP = "usemap"; VALUE = "value"; WIDTH = "width"; WRAP = "wrap";
+}
+
+attributes! {
+    "ARIA attribute",
+    ARIA_ACTIVEDESCENDANT = "aria-activedescendant"; ARIA_ATOMIC = "aria-atomic";
+    ARIA_AUTOCOMPLETE = "aria-autocomplete"; ARIA_BUSY = "aria-busy"; ARIA_CHECKED = "aria-checked";
+    ARIA_COLCOUNT = "aria-colcount"; ARIA_COLINDEX = "aria-colindex"; ARIA_COLSPAN = "aria-colspan";
+    ARIA_CONTROLS = "aria-controls"; ARIA_CURRENT = "aria-current";
+    ARIA_DESCRIBEDBY = "aria-describedby"; ARIA_DETAILS = "aria-details";
+    ARIA_DISABLED = "aria-disabled"; ARIA_ERRORMESSAGE = "aria-errormessage";
+    ARIA_EXPANDED = "aria-expanded"; ARIA_FLOWTO = "aria-flowto"; ARIA_HASPOPUP = "aria-haspopup";
+    ARIA_HIDDEN = "aria-hidden"; ARIA_INVALID = "aria-invalid";
+    ARIA_KEYSHORTCUTS = "aria-keyshortcuts"; ARIA_LABEL = "aria-label";
+    ARIA_LABELLEDBY = "aria-labelledby"; ARIA_LEVEL = "aria-level"; ARIA_LIVE = "aria-live";
+    ARIA_MODAL = "aria-modal"; ARIA_MULTILINE = "aria-multiline";
+    ARIA_MULTISELECTABLE = "aria-multiselectable"; ARIA_ORIENTATION = "aria-orientation";
+    ARIA_OWNS = "aria-owns"; ARIA_PLACEHOLDER = "aria-placeholder"; ARIA_POSINSET = "aria-posinset";
+    ARIA_PRESSED = "aria-pressed"; ARIA_READONLY = "aria-readonly"; ARIA_RELEVANT = "aria-relevant";
+    ARIA_REQUIRED = "aria-required"; ARIA_ROLEDESCRIPTION = "aria-roledescription";
+    ARIA_ROWCOUNT = "aria-rowcount"; ARIA_ROWINDEX = "aria-rowindex"; ARIA_ROWSPAN = "aria-rowspan";
+    ARIA_SELECTED = "aria-selected"; ARIA_SETSIZE = "aria-setsize"; ARIA_SORT = "aria-sort";
+    ARIA_VALUEMAX = "aria-valuemax"; ARIA_VALUEMIN = "aria-valuemin";
+    ARIA_VALUENOW = "aria-valuenow"; ARIA_VALUETEXT = "aria-valuetext";
+}
+
+attributes! {
+    "event handler attribute",
+    ONABORT = "onabort"; ONAFTERPRINT = "onafterprint"; ONAUXCLICK = "onauxclick";
+    ONBEFOREINPUT = "onbeforeinput"; ONBEFOREMATCH = "onbeforematch";
+    ONBEFOREPRINT = "onbeforeprint"; ONBEFORETOGGLE = "onbeforetoggle";
+    ONBEFOREUNLOAD = "onbeforeunload"; ONBLUR = "onblur"; ONCANCEL = "oncancel";
+    ONCANPLAY = "oncanplay"; ONCANPLAYTHROUGH = "oncanplaythrough"; ONCHANGE = "onchange";
+    ONCLICK = "onclick"; ONCLOSE = "onclose"; ONCONTEXTLOST = "oncontextlost";
+    ONCONTEXTMENU = "oncontextmenu"; ONCONTEXTRESTORED = "oncontextrestored"; ONCOPY = "oncopy";
+    ONCUECHANGE = "oncuechange"; ONCUT = "oncut"; ONDBLCLICK = "ondblclick"; ONDRAG = "ondrag";
+    ONDRAGEND = "ondragend"; ONDRAGENTER = "ondragenter"; ONDRAGLEAVE = "ondragleave";
+    ONDRAGOVER = "ondragover"; ONDRAGSTART = "ondragstart"; ONDROP = "ondrop";
+    ONDURATIONCHANGE = "ondurationchange"; ONEMPTIED = "onemptied"; ONENDED = "onended";
+    ONERROR = "onerror"; ONFOCUS = "onfocus"; ONFORMDATA = "onformdata";
+    ONHASHCHANGE = "onhashchange"; ONINPUT = "oninput"; ONINVALID = "oninvalid";
+    ONKEYDOWN = "onkeydown"; ONKEYPRESS = "onkeypress"; ONKEYUP = "onkeyup";
+    ONLANGUAGECHANGE = "onlanguagechange"; ONLOAD = "onload"; ONLOADEDDATA = "onloadeddata";
+    ONLOADEDMETADATA = "onloadedmetadata"; ONLOADSTART = "onloadstart"; ONMESSAGE = "onmessage";
+    ONMESSAGEERROR = "onmessageerror"; ONMOUSEDOWN = "onmousedown"; ONMOUSEENTER = "onmouseenter";
+    ONMOUSELEAVE = "onmouseleave"; ONMOUSEMOVE = "onmousemove"; ONMOUSEOUT = "onmouseout";
+    ONMOUSEOVER = "onmouseover"; ONMOUSEUP = "onmouseup"; ONOFFLINE = "onoffline";
+    ONONLINE = "ononline"; ONPAGEHIDE = "onpagehide"; ONPAGESHOW = "onpageshow";
+    ONPASTE = "onpaste"; ONPAUSE = "onpause"; ONPLAY = "onplay"; ONPLAYING = "onplaying";
+    ONPOINTERCANCEL = "onpointercancel"; ONPOINTERDOWN = "onpointerdown";
+    ONPOINTERENTER = "onpointerenter"; ONPOINTERLEAVE = "onpointerleave";
+    ONPOINTERMOVE = "onpointermove"; ONPOINTEROUT = "onpointerout";
+    ONPOINTEROVER = "onpointerover"; ONPOINTERUP = "onpointerup"; ONPOPSTATE = "onpopstate";
+    ONPROGRESS = "onprogress"; ONRATECHANGE = "onratechange";
+    ONREJECTIONHANDLED = "onrejectionhandled"; ONRESET = "onreset"; ONRESIZE = "onresize";
+    ONSCROLL = "onscroll"; ONSCROLLEND = "onscrollend";
+    ONSECURITYPOLICYVIOLATION = "onsecuritypolicyviolation"; ONSEEKED = "onseeked";
+    ONSEEKING = "onseeking"; ONSELECT = "onselect"; ONSLOTCHANGE = "onslotchange";
+    ONSTALLED = "onstalled"; ONSTORAGE = "onstorage"; ONSUBMIT = "onsubmit";
+    ONSUSPEND = "onsuspend"; ONTIMEUPDATE = "ontimeupdate"; ONTOGGLE = "ontoggle";
+    ONUNHANDLEDREJECTION = "onunhandledrejection"; ONUNLOAD = "onunload";
+    ONVOLUMECHANGE = "onvolumechange"; ONWAITING = "onwaiting"; ONWHEEL = "onwheel";
+}
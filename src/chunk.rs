@@ -0,0 +1,226 @@
+//! Chunk related module. This contains a writer splitting the output in chunks of bytes,
+//! to start streaming a page while the rest of it is still being built.
+//!
+//! The [ChunkedWriter] implements [std::io::Write], so it can be used with the
+//! [Buffer](crate::Buffer) like any other writer. Each completed chunk is handed to
+//! a [ChunkSink], like a channel or a callback.
+//!
+//! ```rust
+//! use another_html_builder::chunk::ChunkedWriter;
+//! use another_html_builder::Buffer;
+//!
+//! let (sender, receiver) = std::sync::mpsc::channel();
+//! std::thread::spawn(move || {
+//!     let writer = ChunkedWriter::new(sender, 8);
+//!     let buf = Buffer::from(writer)
+//!         .node("p")
+//!         .content(|buf| buf.text("Hello World!"));
+//!     buf.into_inner().finish().unwrap();
+//! });
+//! let chunks: Vec<Vec<u8>> = receiver.iter().collect();
+//! assert_eq!(chunks.len(), 3);
+//! assert_eq!(chunks.concat(), b"<p>Hello World!</p>");
+//! ```
+
+/// Default size of the chunks, in bytes.
+pub const DEFAULT_CHUNK_SIZE: usize = 8 * 1024;
+
+/// Consumer of the chunks produced by a [ChunkedWriter].
+pub trait ChunkSink {
+    /// Receives a chunk of bytes.
+    ///
+    /// An error stops the writing, like when the receiving side of a channel is closed.
+    fn send(&mut self, chunk: Vec<u8>) -> std::io::Result<()>;
+}
+
+fn disconnected() -> std::io::Error {
+    std::io::Error::new(
+        std::io::ErrorKind::BrokenPipe,
+        "the chunk receiver has been dropped",
+    )
+}
+
+impl<F: FnMut(Vec<u8>) -> std::io::Result<()>> ChunkSink for F {
+    fn send(&mut self, chunk: Vec<u8>) -> std::io::Result<()> {
+        self(chunk)
+    }
+}
+
+impl ChunkSink for std::sync::mpsc::Sender<Vec<u8>> {
+    fn send(&mut self, chunk: Vec<u8>) -> std::io::Result<()> {
+        std::sync::mpsc::Sender::send(self, chunk).map_err(|_| disconnected())
+    }
+}
+
+/// Blocks until the receiver has room for the chunk, which limits the memory used
+/// when the consumer is slower than the rendering.
+impl ChunkSink for std::sync::mpsc::SyncSender<Vec<u8>> {
+    fn send(&mut self, chunk: Vec<u8>) -> std::io::Result<()> {
+        std::sync::mpsc::SyncSender::send(self, chunk).map_err(|_| disconnected())
+    }
+}
+
+/// The receiving side implements `futures::Stream`.
+#[cfg(feature = "futures")]
+impl ChunkSink for futures_channel::mpsc::UnboundedSender<Vec<u8>> {
+    fn send(&mut self, chunk: Vec<u8>) -> std::io::Result<()> {
+        self.unbounded_send(chunk).map_err(|_| disconnected())
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl ChunkSink for tokio::sync::mpsc::UnboundedSender<Vec<u8>> {
+    fn send(&mut self, chunk: Vec<u8>) -> std::io::Result<()> {
+        tokio::sync::mpsc::UnboundedSender::send(self, chunk).map_err(|_| disconnected())
+    }
+}
+
+/// Writer accumulating the output in chunks of a fixed size.
+///
+/// A chunk is sent to the [ChunkSink] as soon as it's full, and flushing the writer
+/// sends the incomplete chunk. The chunks are split on bytes, so a multi-byte character
+/// can be split between two chunks.
+///
+/// The content that has not been sent is lost when the writer is dropped, call
+/// [ChunkedWriter::finish] once the page is written.
+///
+/// When the sink returns an error, the chunk it was given is lost and the writer
+/// must not be used anymore: every following write or flush fails, so nothing is sent
+/// after a missing chunk. The chunks sent before the error have been delivered.
+pub struct ChunkedWriter<S> {
+    sink: S,
+    size: usize,
+    chunk: Vec<u8>,
+    failed: bool,
+}
+
+impl<S: ChunkSink> ChunkedWriter<S> {
+    /// Creates a writer sending chunks of `size` bytes to the sink.
+    pub fn new(sink: S, size: usize) -> Self {
+        let size = size.max(1);
+        Self {
+            sink,
+            size,
+            chunk: Vec::with_capacity(size),
+            failed: false,
+        }
+    }
+
+    /// Creates a writer sending chunks of [DEFAULT_CHUNK_SIZE] bytes to the sink.
+    pub fn with_default_size(sink: S) -> Self {
+        Self::new(sink, DEFAULT_CHUNK_SIZE)
+    }
+
+    fn send_chunk(&mut self) -> std::io::Result<()> {
+        let chunk = std::mem::replace(&mut self.chunk, Vec::with_capacity(self.size));
+        let result = self.sink.send(chunk);
+        self.failed = result.is_err();
+        result
+    }
+
+    fn check(&self) -> std::io::Result<()> {
+        if self.failed {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::BrokenPipe,
+                "a previous chunk could not be sent",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Sends the last incomplete chunk and returns the sink.
+    pub fn finish(mut self) -> std::io::Result<S> {
+        std::io::Write::flush(&mut self)?;
+        Ok(self.sink)
+    }
+}
+
+impl<S: ChunkSink> std::io::Write for ChunkedWriter<S> {
+    /// Fills the current chunk and sends it when it's full, so a single chunk is sent
+    /// at most. The error of the sink is returned instead of the number of bytes when
+    /// sending fails.
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.check()?;
+        let written = buf.len().min(self.size - self.chunk.len());
+        self.chunk.extend_from_slice(&buf[..written]);
+        if self.chunk.len() == self.size {
+            self.send_chunk()?;
+        }
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.check()?;
+        if self.chunk.is_empty() {
+            return Ok(());
+        }
+        self.send_chunk()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::ChunkedWriter;
+
+    #[test_case::test_case(4, &["abcd", "efgh", "ij"]; "with smaller chunks")]
+    #[test_case::test_case(5, &["abcde", "fghij"]; "with exact chunks")]
+    #[test_case::test_case(32, &["abcdefghij"]; "with bigger chunk")]
+    #[test_case::test_case(0, &["a", "b", "c", "d", "e", "f", "g", "h", "i", "j"]; "with empty chunk size")]
+    fn splitting_chunks(size: usize, expected: &[&str]) {
+        let mut chunks = Vec::new();
+        let mut writer = ChunkedWriter::new(
+            |chunk: Vec<u8>| {
+                chunks.push(String::from_utf8(chunk).unwrap());
+                Ok(())
+            },
+            size,
+        );
+        writer.write_all(b"abc").unwrap();
+        writer.write_all(b"defghij").unwrap();
+        let _sink = writer.finish().unwrap();
+        assert_eq!(chunks, expected);
+    }
+
+    #[test]
+    fn flushing_incomplete_chunk() {
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let mut writer = ChunkedWriter::new(sender, 16);
+        writer.write_all(b"<head>").unwrap();
+        writer.flush().unwrap();
+        writer.flush().unwrap();
+        assert_eq!(receiver.try_recv().unwrap(), b"<head>");
+        assert!(receiver.try_recv().is_err());
+    }
+
+    #[test]
+    fn writing_to_closed_channel() {
+        let (sender, receiver) = std::sync::mpsc::channel();
+        drop(receiver);
+        let mut writer = ChunkedWriter::new(sender, 2);
+        let error = writer.write_all(b"abc").unwrap_err();
+        assert_eq!(error.kind(), std::io::ErrorKind::BrokenPipe);
+    }
+
+    #[test]
+    fn writing_after_error() {
+        let mut chunks = Vec::new();
+        let mut writer = ChunkedWriter::new(
+            |chunk: Vec<u8>| {
+                if chunk == b"cd" {
+                    return Err(std::io::ErrorKind::ConnectionReset.into());
+                }
+                chunks.push(chunk);
+                Ok(())
+            },
+            2,
+        );
+        let error = writer.write_all(b"abcdef").unwrap_err();
+        assert_eq!(error.kind(), std::io::ErrorKind::ConnectionReset);
+        assert!(writer.write(b"g").is_err());
+        assert!(writer.flush().is_err());
+        assert!(writer.finish().is_err());
+        assert_eq!(chunks, [b"ab".to_vec()]);
+    }
+}
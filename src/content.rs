@@ -1,7 +1,5 @@
 //! Attribute related module. This contains a wrapper to escape values.
 
-const CONTENT_ESCAPE: [char; 6] = ['&', '<', '>', '"', '\'', '/'];
-
 /// Wrapper around a [str] that will escape the content when writing.
 ///
 /// This implementation will transform:
@@ -15,34 +13,200 @@ pub struct EscapedContent<'a>(pub &'a str);
 
 impl std::fmt::Display for EscapedContent<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if self.0.is_empty() {
-            return Ok(());
+        crate::escape::CONTENT.write(f, self.0)
+    }
+}
+
+/// Characters escaped in the text content, see [Buffer::with_escaping](crate::Buffer::with_escaping).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Escaping {
+    /// Only escapes what html requires in the content of an element, `&` and `<`.
+    ///
+    /// This produces the smallest output, but is not suited for xml documents, where
+    /// the `]]>` sequence is not allowed.
+    Minimal,
+    /// Escapes the characters recommended by OWASP, like [EscapedContent].
+    Strict,
+    /// Escapes like [Escaping::Strict] and writes every non ascii character as a
+    /// numeric character reference, like `&#xE9;`. The non ascii characters of the
+    /// attribute values are written as references too, like `&#233;`.
+    ///
+    /// As long as the element and attribute names are ascii, the output only contains
+    /// ascii characters, so it can be sent with a legacy encoding or to email clients
+    /// that don't handle utf-8.
+    AsciiOnly,
+}
+
+/// Wrapper around a [str] that will escape the content when writing, following
+/// the given [Escaping] policy.
+///
+/// ```rust
+/// use another_html_builder::content::{EscapedText, Escaping};
+///
+/// assert_eq!(
+///     EscapedText("l'été > <b>", Escaping::Minimal).to_string(),
+///     "l'été > &lt;b>"
+/// );
+/// assert_eq!(
+///     EscapedText("l'été > <b>", Escaping::Strict).to_string(),
+///     "l&#x27;été &gt; &lt;b&gt;"
+/// );
+/// assert_eq!(
+///     EscapedText("l'été > <b>", Escaping::AsciiOnly).to_string(),
+///     "l&#x27;&#xE9;t&#xE9; &gt; &lt;b&gt;"
+/// );
+/// ```
+pub struct EscapedText<'a>(pub &'a str, pub Escaping);
+
+impl std::fmt::Display for EscapedText<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        crate::escape::text(self.1).write(f, self.0)
+    }
+}
+
+/// Wrapper around a [str] that will only escape what xml requires when writing.
+///
+/// This implementation will transform:
+/// - `&` to `&amp;`
+/// - `<` to `&lt;`
+/// - `>` to `&gt;`
+pub struct EscapedXmlContent<'a>(pub &'a str);
+
+impl std::fmt::Display for EscapedXmlContent<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        crate::escape::XML_CONTENT.write(f, self.0)
+    }
+}
+
+/// Writes the content of a raw text element, neutralizing the sequences that would
+/// end the element early or change the way the parser reads it.
+///
+/// Each sequence is found case insensitively and its leading `<` is replaced by `escaped`.
+/// The `NUL` characters are replaced by `U+FFFD`, like an html parser would do.
+/// Inside a `CDATA` section, the `]]>` sequence is split in two sections.
+fn write_raw_text(
+    f: &mut std::fmt::Formatter<'_>,
+    input: &str,
+    sequences: &[&str],
+    escaped: &str,
+    cdata: bool,
+) -> std::fmt::Result {
+    let mut start: usize = 0;
+    let mut offset: usize = 0;
+    while let Some(index) = input[offset..].find(|c| matches!(c, '<' | '\0') || (cdata && c == ']'))
+    {
+        let begin = offset + index;
+        offset = begin + 1;
+        if input.as_bytes()[begin] == b'\0' {
+            f.write_str(&input[start..begin])?;
+            f.write_str("\u{FFFD}")?;
+            start = offset;
+            continue;
         }
-        let mut start: usize = 0;
-        while let Some(index) = self.0[start..].find(CONTENT_ESCAPE) {
-            if index > 0 {
-                f.write_str(&self.0[start..(start + index)])?;
+        if input.as_bytes()[begin] == b']' {
+            if input[begin..].starts_with("]]>") {
+                offset = begin + 2;
+                f.write_str(&input[start..offset])?;
+                f.write_str("]]><![CDATA[")?;
+                start = offset;
             }
-            let begin = start + index;
-            debug_assert!(start <= begin);
-            let end = begin + 1;
-            debug_assert!(begin < self.0.len());
-            debug_assert!(begin < end);
-            debug_assert!(end <= self.0.len());
-            match &self.0[begin..end] {
-                "&" => f.write_str("&amp;")?,
-                "<" => f.write_str("&lt;")?,
-                ">" => f.write_str("&gt;")?,
-                "\"" => f.write_str("&quot;")?,
-                "'" => f.write_str("&#x27;")?,
-                "/" => f.write_str("&#x2F;")?,
-                other => f.write_str(other)?,
-            };
-            start = end;
-            debug_assert!(start <= self.0.len());
+            continue;
+        }
+        let rest = &input.as_bytes()[offset..];
+        let found = sequences.iter().any(|seq| {
+            rest.len() >= seq.len() && rest[..seq.len()].eq_ignore_ascii_case(seq.as_bytes())
+        });
+        if found {
+            f.write_str(&input[start..begin])?;
+            f.write_str(escaped)?;
+            start = offset;
         }
-        f.write_str(&self.0[start..])?;
-        Ok(())
+    }
+    f.write_str(&input[start..])
+}
+
+const SCRIPT_SEQUENCES: [&str; 3] = ["/script", "script", "!--"];
+const STYLE_SEQUENCES: [&str; 2] = ["/style", "!--"];
+
+/// Wrapper around a [str] that will be written as the content of a `script` element.
+///
+/// The content is not escaped with character references, but the `<` starting
+/// `</script`, `<script` and `<!--` is replaced by `\x3C`, which is equivalent in javascript
+/// strings, template literals and regular expressions.
+///
+/// A `NUL` character cannot be represented in a script element, so it's replaced by `U+FFFD`.
+/// [Buffer::try_script](crate::Buffer::try_script) rejects it instead.
+pub struct EscapedScript<'a>(pub &'a str);
+
+impl std::fmt::Display for EscapedScript<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_raw_text(f, self.0, &SCRIPT_SEQUENCES, "\\x3C", false)
+    }
+}
+
+/// Wrapper around a [str] that will be written as the content of a `style` element.
+///
+/// The content is not escaped with character references, but the `<` starting
+/// `</style` and `<!--` is replaced by the css escape `\3C`.
+///
+/// A `NUL` character cannot be represented in a style element, so it's replaced by `U+FFFD`.
+/// [Buffer::try_style](crate::Buffer::try_style) rejects it instead.
+pub struct EscapedStyle<'a>(pub &'a str);
+
+impl std::fmt::Display for EscapedStyle<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_raw_text(f, self.0, &STYLE_SEQUENCES, "\\3C", false)
+    }
+}
+
+/// Writes the content of a raw text element in a `CDATA` section, for the xml dialects.
+///
+/// The markers of the section are in comments, so the content stays valid when the
+/// document is parsed as html.
+fn write_cdata(
+    f: &mut std::fmt::Formatter<'_>,
+    input: &str,
+    sequences: &[&str],
+    escaped: &str,
+) -> std::fmt::Result {
+    f.write_str("/*<![CDATA[*/")?;
+    write_raw_text(f, input, sequences, escaped, true)?;
+    f.write_str("/*]]>*/")
+}
+
+/// Wrapper around a [str] that will be written as the content of a `script` element
+/// of an xhtml or xml document.
+///
+/// The content is escaped like [EscapedScript] and wrapped in a `CDATA` section, so
+/// that `<` and `&` keep the document well formed. The `]]>` sequence is split in two
+/// sections.
+///
+/// ```rust
+/// use another_html_builder::content::EscapedXmlScript;
+///
+/// assert_eq!(
+///     EscapedXmlScript("a[b[0]]>c && d").to_string(),
+///     "/*<![CDATA[*/a[b[0]]]]><![CDATA[>c && d/*]]>*/"
+/// );
+/// ```
+pub struct EscapedXmlScript<'a>(pub &'a str);
+
+impl std::fmt::Display for EscapedXmlScript<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_cdata(f, self.0, &SCRIPT_SEQUENCES, "\\x3C")
+    }
+}
+
+/// Wrapper around a [str] that will be written as the content of a `style` element
+/// of an xhtml or xml document.
+///
+/// The content is escaped like [EscapedStyle] and wrapped in a `CDATA` section, like
+/// [EscapedXmlScript].
+pub struct EscapedXmlStyle<'a>(pub &'a str);
+
+impl std::fmt::Display for EscapedXmlStyle<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_cdata(f, self.0, &STYLE_SEQUENCES, "\\3C")
     }
 }
 
@@ -55,4 +219,60 @@ mod tests {
     fn escaping_content(input: &str, expected: &str) {
         assert_eq!(format!("{}", super::EscapedContent(input)), expected);
     }
+
+    #[test_case::test_case("a<b>&'c'/\"d\"", crate::content::Escaping::Minimal, "a&lt;b>&amp;'c'/\"d\""; "minimal")]
+    #[test_case::test_case("a<b>&'c'/\"d\"", crate::content::Escaping::Strict, "a&lt;b&gt;&amp;&#x27;c&#x27;&#x2F;&quot;d&quot;"; "strict")]
+    #[test_case::test_case("ça<va>", crate::content::Escaping::AsciiOnly, "&#xE7;a&lt;va&gt;"; "ascii only")]
+    fn escaping_text(input: &str, escaping: crate::content::Escaping, expected: &str) {
+        assert_eq!(format!("{}", super::EscapedText(input, escaping)), expected);
+    }
+
+    #[test_case::test_case("hello world", "hello world"; "without character to escape")]
+    #[test_case::test_case("a<b>&'c'/\"d\"", "a&lt;b&gt;&amp;'c'/\"d\""; "with specials")]
+    fn escaping_xml_content(input: &str, expected: &str) {
+        assert_eq!(format!("{}", super::EscapedXmlContent(input)), expected);
+    }
+
+    #[test_case::test_case("alert(1 < 2)", "alert(1 < 2)"; "without sequence to escape")]
+    #[test_case::test_case("let a = '</script><b>';", "let a = '\\x3C/script><b>';"; "with closing tag")]
+    #[test_case::test_case("'</SCRIPT >'", "'\\x3C/SCRIPT >'"; "with uppercase closing tag")]
+    #[test_case::test_case("'<!--<script>'", "'\\x3C!--\\x3Cscript>'"; "with comment and opening tag")]
+    #[test_case::test_case("'<'", "'<'"; "with bracket at the end")]
+    fn escaping_script(input: &str, expected: &str) {
+        assert_eq!(format!("{}", super::EscapedScript(input)), expected);
+    }
+
+    #[test_case::test_case("a::after { content: '</style>' }", "a::after { content: '\\3C/style>' }"; "with closing tag")]
+    #[test_case::test_case("a::after { content: '<!--' }", "a::after { content: '\\3C!--' }"; "with comment")]
+    #[test_case::test_case("a::after { content: '</script>' }", "a::after { content: '</script>' }"; "with other closing tag")]
+    fn escaping_style(input: &str, expected: &str) {
+        assert_eq!(format!("{}", super::EscapedStyle(input)), expected);
+    }
+
+    #[test_case::test_case("a && b < c", "/*<![CDATA[*/a && b < c/*]]>*/"; "without sequence to escape")]
+    #[test_case::test_case("'</script>]]>'", "/*<![CDATA[*/'\\x3C/script>]]]]><![CDATA[>'/*]]>*/"; "with closing tag and cdata end")]
+    #[test_case::test_case("a]]]>b]]", "/*<![CDATA[*/a]]]]]><![CDATA[>b]]/*]]>*/"; "with brackets")]
+    fn escaping_xml_script(input: &str, expected: &str) {
+        assert_eq!(format!("{}", super::EscapedXmlScript(input)), expected);
+    }
+
+    #[test]
+    fn escaping_xml_style() {
+        assert_eq!(
+            super::EscapedXmlStyle("a > b::after { content: ']]></style>' }").to_string(),
+            "/*<![CDATA[*/a > b::after { content: ']]]]><![CDATA[>\\3C/style>' }/*]]>*/"
+        );
+    }
+
+    #[test]
+    fn escaping_raw_text_with_nul() {
+        assert_eq!(
+            super::EscapedScript("a\0<!--").to_string(),
+            "a\u{FFFD}\\x3C!--"
+        );
+        assert_eq!(
+            super::EscapedStyle("\0a\0").to_string(),
+            "\u{FFFD}a\u{FFFD}"
+        );
+    }
 }
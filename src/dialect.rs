@@ -0,0 +1,97 @@
+//! Serialization related module. This defines how the [Buffer](crate::Buffer) writes
+//! the elements depending on the kind of document being generated.
+
+/// List of the html void elements, that cannot have any content.
+///
+/// See <https://html.spec.whatwg.org/multipage/syntax.html#void-elements>.
+const VOID_ELEMENTS: [&str; 13] = [
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "source", "track",
+    "wbr",
+];
+
+/// Checks if the given tag name is an html void element.
+///
+/// ```rust
+/// use another_html_builder::dialect::is_void_element;
+///
+/// assert!(is_void_element("br"));
+/// assert!(is_void_element("IMG"));
+/// assert!(!is_void_element("div"));
+/// ```
+pub fn is_void_element(name: &str) -> bool {
+    VOID_ELEMENTS
+        .iter()
+        .any(|item| item.eq_ignore_ascii_case(name))
+}
+
+/// Serialization mode used by the [Buffer](crate::Buffer).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Dialect {
+    /// Html5 serialization.
+    ///
+    /// Void elements are written without closing tag (`<br>`) and every other
+    /// element is closed explicitly (`<div></div>`).
+    #[default]
+    Html5,
+    /// Xhtml serialization.
+    ///
+    /// The doctype is preceded by an xml declaration, every element without content
+    /// is self closing (`<br />`, `<div />`) and boolean attributes are written with
+    /// their name as value (`selected="selected"`).
+    Xhtml,
+    /// Generic xml serialization.
+    ///
+    /// Behaves like [Dialect::Xhtml] but only writes the xml declaration as doctype
+    /// and only escapes the characters required by xml in text content.
+    Xml,
+}
+
+impl Dialect {
+    /// Returns the prolog written by [Buffer::doctype](crate::Buffer::doctype).
+    pub(crate) fn doctype(self) -> &'static str {
+        match self {
+            Self::Html5 => "<!DOCTYPE html>",
+            Self::Xhtml => "<?xml version=\"1.0\" encoding=\"UTF-8\"?><!DOCTYPE html>",
+            Self::Xml => "<?xml version=\"1.0\" encoding=\"UTF-8\"?>",
+        }
+    }
+
+    /// Returns `true` when attributes without value can be written with only their name.
+    pub(crate) fn minimizes_attributes(self) -> bool {
+        matches!(self, Self::Html5)
+    }
+
+    /// Returns what should be written to close an element without any content.
+    pub(crate) fn empty_element_end(self, name: &str) -> EmptyElementEnd {
+        match self {
+            Self::Html5 if is_void_element(name) => EmptyElementEnd::Void,
+            Self::Html5 => EmptyElementEnd::ClosingTag,
+            Self::Xhtml | Self::Xml => EmptyElementEnd::SelfClosing,
+        }
+    }
+}
+
+/// How an element without content should be terminated.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum EmptyElementEnd {
+    /// `<br>`
+    Void,
+    /// `<div></div>`
+    ClosingTag,
+    /// `<div />`
+    SelfClosing,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Dialect, EmptyElementEnd};
+
+    #[test_case::test_case(Dialect::Html5, "br", EmptyElementEnd::Void; "html5 void element")]
+    #[test_case::test_case(Dialect::Html5, "div", EmptyElementEnd::ClosingTag; "html5 normal element")]
+    #[test_case::test_case(Dialect::Xhtml, "br", EmptyElementEnd::SelfClosing; "xhtml void element")]
+    #[test_case::test_case(Dialect::Xhtml, "div", EmptyElementEnd::SelfClosing; "xhtml normal element")]
+    #[test_case::test_case(Dialect::Xml, "entry", EmptyElementEnd::SelfClosing; "xml element")]
+    fn empty_element_end(dialect: Dialect, name: &str, expected: EmptyElementEnd) {
+        assert_eq!(dialect.empty_element_end(name), expected);
+    }
+}
@@ -0,0 +1,59 @@
+//! Element related module. This contains a constant for every standard html element.
+//!
+//! The constants are validated at compile time and can be used with [Buffer::node](crate::Buffer::node)
+//! as any other tag name, so a typo fails the compilation. Custom elements can still be
+//! written with a [str].
+//!
+//! ```rust
+//! use another_html_builder::attrs;
+//! use another_html_builder::elements::{DIV, P};
+//!
+//! let html = another_html_builder::Buffer::default()
+//!     .node(DIV)
+//!     .attr((attrs::CLASS, "card"))
+//!     .content(|buf| buf.node(P).content(|buf| buf.text("Hello")))
+//!     .node("my-element")
+//!     .close()
+//!     .into_inner();
+//! assert_eq!(
+//!     html,
+//!     "<div class=\"card\"><p>Hello</p></div><my-element></my-element>"
+//! );
+//! ```
+//!
+//! ```compile_fail
+//! use another_html_builder::elements::DVI;
+//! ```
+
+macro_rules! elements {
+    ($($constant:ident = $name:literal;)*) => {
+        $(
+            #[doc = concat!("The `<", $name, ">` element.")]
+            pub const $constant: &str = crate::name::tag_name($name);
+        )*
+    };
+}
+
+elements! {
+    HTML = "html"; HEAD = "head"; TITLE = "title"; BASE = "base"; LINK = "link"; META = "meta";
+    STYLE = "style"; BODY = "body"; ARTICLE = "article"; SECTION = "section"; NAV = "nav";
+    ASIDE = "aside"; H1 = "h1"; H2 = "h2"; H3 = "h3"; H4 = "h4"; H5 = "h5"; H6 = "h6";
+    HGROUP = "hgroup"; HEADER = "header"; FOOTER = "footer"; ADDRESS = "address"; P = "p";
+    HR = "hr"; PRE = "pre"; BLOCKQUOTE = "blockquote"; OL = "ol"; UL = "ul"; MENU = "menu";
+    LI = "li"; DL = "dl"; DT = "dt"; DD = "dd"; FIGURE = "figure"; FIGCAPTION = "figcaption";
+    MAIN = "main"; SEARCH = "search"; DIV = "div"; A = "a"; EM = "em"; STRONG = "strong";
+    SMALL = "small"; S = "s"; CITE = "cite"; Q = "q"; DFN = "dfn"; ABBR = "abbr";
+    RUBY = "ruby"; RT = "rt"; RP = "rp"; DATA = "data"; TIME = "time"; CODE = "code";
+    VAR = "var"; SAMP = "samp"; KBD = "kbd"; SUB = "sub"; SUP = "sup"; I = "i"; B = "b";
+    U = "u"; MARK = "mark"; BDI = "bdi"; BDO = "bdo"; SPAN = "span"; BR = "br"; WBR = "wbr";
+    INS = "ins"; DEL = "del"; PICTURE = "picture"; SOURCE = "source"; IMG = "img";
+    IFRAME = "iframe"; EMBED = "embed"; OBJECT = "object"; VIDEO = "video"; AUDIO = "audio";
+    TRACK = "track"; MAP = "map"; AREA = "area"; TABLE = "table"; CAPTION = "caption";
+    COLGROUP = "colgroup"; COL = "col"; TBODY = "tbody"; THEAD = "thead"; TFOOT = "tfoot";
+    TR = "tr"; TD = "td"; TH = "th"; FORM = "form"; LABEL = "label"; INPUT = "input";
+    BUTTON = "button"; SELECT = "select"; DATALIST = "datalist"; OPTGROUP = "optgroup";
+    OPTION = "option"; TEXTAREA = "textarea"; OUTPUT = "output"; PROGRESS = "progress";
+    METER = "meter"; FIELDSET = "fieldset"; LEGEND = "legend"; DETAILS = "details";
+    SUMMARY = "summary"; DIALOG = "dialog"; SCRIPT = "script"; NOSCRIPT = "noscript";
+    TEMPLATE = "template"; SLOT = "slot"; CANVAS = "canvas"; SVG = "svg"; MATH = "math";
+}
@@ -0,0 +1,169 @@
+//! Error related module. This contains the error returned by the fallible methods of the [Buffer](crate::Buffer).
+
+/// Operation of the [Buffer](crate::Buffer) that failed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Operation {
+    /// Writing the doctype.
+    Doctype,
+    /// Checking the name of an element, before writing anything.
+    TagName,
+    /// Writing the opening tag of an element.
+    OpenTag,
+    /// Checking the name of an attribute, before writing anything.
+    AttributeName,
+    /// Writing an attribute.
+    Attribute,
+    /// Writing escaped content, like text, script, style or json.
+    Text,
+    /// Writing raw content.
+    Raw,
+    /// Writing the end of an element.
+    CloseTag,
+    /// Flushing the writer.
+    Flush,
+}
+
+impl std::fmt::Display for Operation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Doctype => "doctype",
+            Self::TagName => "tag name",
+            Self::OpenTag => "opening tag",
+            Self::AttributeName => "attribute name",
+            Self::Attribute => "attribute",
+            Self::Text => "text",
+            Self::Raw => "raw content",
+            Self::CloseTag => "closing tag",
+            Self::Flush => "flush",
+        })
+    }
+}
+
+/// Reason why an [Operation] failed.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Cause<E> {
+    /// The writer returned an error.
+    Writer(E),
+    /// The content of a `script` or `style` element contains a `NUL` character,
+    /// which cannot be represented in those elements.
+    NulCharacter,
+    /// The tag or attribute name cannot be written as is, because it could end the tag
+    /// or the attribute early, see [name](crate::name).
+    InvalidName(String),
+    /// The value cannot be serialized as json, see [Buffer::try_json](crate::Buffer::try_json).
+    #[cfg(feature = "serde")]
+    Json(serde_json::Error),
+}
+
+impl<E> std::fmt::Display for Cause<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Writer(_) => f.write_str("the writer failed"),
+            Self::NulCharacter => f.write_str("the content contains a NUL character"),
+            Self::InvalidName(name) => write!(f, "the name `{name}` is not valid"),
+            #[cfg(feature = "serde")]
+            Self::Json(_) => f.write_str("the value cannot be serialized as json"),
+        }
+    }
+}
+
+impl<E: std::error::Error + 'static> std::error::Error for Cause<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Writer(inner) => Some(inner),
+            Self::NulCharacter | Self::InvalidName(_) => None,
+            #[cfg(feature = "serde")]
+            Self::Json(inner) => Some(inner),
+        }
+    }
+}
+
+/// Error of the [Buffer](crate::Buffer), with the position in the document where it happened.
+///
+/// The path is the one given by [Body::path](crate::Body::path) for the element
+/// being written. Nothing is written to the writer when the content is rejected
+/// before being written, like with [Cause::NulCharacter] or [Cause::InvalidName].
+///
+/// ```rust
+/// use another_html_builder::{Cause, Operation};
+///
+/// let error = another_html_builder::Buffer::default()
+///     .node("body")
+///     .try_content(|buf| buf.node("script").try_content(|buf| buf.try_script("\0")))
+///     .err()
+///     .unwrap();
+/// assert_eq!(error.path(), "$ > body > script");
+/// assert_eq!(error.operation(), Operation::Text);
+/// assert!(matches!(error.cause(), Cause::NulCharacter));
+/// assert_eq!(
+///     error.to_string(),
+///     "unable to write the text at `$ > body > script`"
+/// );
+/// ```
+#[derive(Debug)]
+pub struct Error<E> {
+    cause: Cause<E>,
+    path: String,
+    operation: Operation,
+}
+
+impl<E> Error<E> {
+    pub(crate) fn new(cause: Cause<E>, path: String, operation: Operation) -> Self {
+        Self {
+            cause,
+            path,
+            operation,
+        }
+    }
+
+    /// Path of the element being written when the error happened.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Operation that failed.
+    pub fn operation(&self) -> Operation {
+        self.operation
+    }
+
+    /// Reason why the operation failed.
+    pub fn cause(&self) -> &Cause<E> {
+        &self.cause
+    }
+
+    pub fn into_cause(self) -> Cause<E> {
+        self.cause
+    }
+
+    /// Error returned by the writer, if the writer failed.
+    pub fn inner(&self) -> Option<&E> {
+        match &self.cause {
+            Cause::Writer(inner) => Some(inner),
+            _ => None,
+        }
+    }
+
+    pub fn into_inner(self) -> Option<E> {
+        match self.cause {
+            Cause::Writer(inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+impl<E> std::fmt::Display for Error<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "unable to write the {} at `{}`",
+            self.operation, self.path
+        )
+    }
+}
+
+impl<E: std::error::Error + 'static> std::error::Error for Error<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.cause)
+    }
+}
@@ -0,0 +1,362 @@
+//! Escaping related module. This contains the engine shared by the wrappers of the
+//! [content](crate::content) and [attribute](crate::attribute) modules, and a writer
+//! to escape any [Display](std::fmt::Display) value without allocating.
+//!
+//! Each escaper has a lookup table giving the replacement of every byte. The
+//! input is scanned for the next byte to replace, 16 bytes at a time with SSE2 on
+//! `x86_64`, or one byte at a time with the table on the other targets.
+
+use crate::content::Escaping;
+
+/// Set of ascii characters to replace when writing, optionally with every non ascii
+/// character.
+pub(crate) struct Escaper {
+    /// Characters to replace, used for scanning the input by blocks.
+    #[cfg_attr(
+        not(all(target_arch = "x86_64", target_feature = "sse2")),
+        allow(dead_code)
+    )]
+    needles: &'static [u8],
+    /// Replacement of each byte, `None` when the byte is written as is.
+    table: [Option<&'static str>; 256],
+    /// Bytes starting a sequence to replace.
+    matches: [bool; 256],
+    /// Writes the non ascii characters as numeric character references.
+    non_ascii: Option<Reference>,
+}
+
+/// Form of the numeric character references replacing the non ascii characters.
+#[derive(Clone, Copy)]
+enum Reference {
+    /// Like `&#xE9;`.
+    Hexadecimal,
+    /// Like `&#233;`.
+    Decimal,
+}
+
+impl Escaper {
+    /// Builds the escaper from a list of characters and their replacements.
+    ///
+    /// Only ascii characters can be replaced, so the input is always split on
+    /// character boundaries.
+    const fn new(needles: &'static [u8], replacements: &'static [&'static str]) -> Self {
+        assert!(needles.len() == replacements.len());
+        let mut table = [None; 256];
+        let mut matches = [false; 256];
+        let mut index = 0;
+        while index < needles.len() {
+            assert!(needles[index].is_ascii());
+            table[needles[index] as usize] = Some(replacements[index]);
+            matches[needles[index] as usize] = true;
+            index += 1;
+        }
+        Self {
+            needles,
+            table,
+            matches,
+            non_ascii: None,
+        }
+    }
+
+    /// Also replaces the non ascii characters by numeric character references.
+    const fn with_non_ascii(mut self, reference: Reference) -> Self {
+        let mut index = 0x80;
+        while index < 256 {
+            self.matches[index] = true;
+            index += 1;
+        }
+        self.non_ascii = Some(reference);
+        self
+    }
+
+    /// Returns the position of the first byte to replace.
+    #[inline]
+    fn find(&self, bytes: &[u8]) -> Option<usize> {
+        #[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+        {
+            let blocks = bytes.len() / sse2::BLOCK_SIZE * sse2::BLOCK_SIZE;
+            // SAFETY: the module is only compiled when sse2 is enabled for the target
+            if let Some(index) =
+                unsafe { sse2::find(self.needles, self.non_ascii.is_some(), &bytes[..blocks]) }
+            {
+                return Some(index);
+            }
+            self.find_scalar(&bytes[blocks..])
+                .map(|index| blocks + index)
+        }
+        #[cfg(not(all(target_arch = "x86_64", target_feature = "sse2")))]
+        {
+            self.find_scalar(bytes)
+        }
+    }
+
+    #[inline]
+    fn find_scalar(&self, bytes: &[u8]) -> Option<usize> {
+        bytes.iter().position(|byte| self.matches[*byte as usize])
+    }
+
+    /// Writes the input, replacing the characters of the escaper.
+    pub(crate) fn write<W: std::fmt::Write + ?Sized>(
+        &self,
+        writer: &mut W,
+        input: &str,
+    ) -> std::fmt::Result {
+        let bytes = input.as_bytes();
+        let mut start: usize = 0;
+        while let Some(index) = self.find(&bytes[start..]) {
+            let begin = start + index;
+            if index > 0 {
+                writer.write_str(&input[start..begin])?;
+            }
+            if let Some(replacement) = self.table[bytes[begin] as usize] {
+                writer.write_str(replacement)?;
+                start = begin + 1;
+            } else if let Some(character) = input[begin..].chars().next() {
+                match self.non_ascii {
+                    Some(Reference::Decimal) => write!(writer, "&#{};", character as u32)?,
+                    Some(Reference::Hexadecimal) | None => {
+                        write!(writer, "&#x{:X};", character as u32)?
+                    }
+                }
+                start = begin + character.len_utf8();
+            }
+        }
+        writer.write_str(&input[start..])
+    }
+}
+
+#[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+mod sse2 {
+    use std::arch::x86_64::{
+        __m128i, _mm_cmpeq_epi8, _mm_loadu_si128, _mm_movemask_epi8, _mm_or_si128, _mm_set1_epi8,
+        _mm_setzero_si128,
+    };
+
+    pub(super) const BLOCK_SIZE: usize = 16;
+
+    /// Returns the position of the first needle, or the first non ascii byte when
+    /// `non_ascii` is set. The length of the input must be a multiple of [BLOCK_SIZE].
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    pub(super) fn find(needles: &[u8], non_ascii: bool, bytes: &[u8]) -> Option<usize> {
+        debug_assert!(bytes.len().is_multiple_of(BLOCK_SIZE));
+        for (index, block) in bytes.chunks_exact(BLOCK_SIZE).enumerate() {
+            // SAFETY: the block is 16 bytes long, and the load doesn't require alignment
+            let block = unsafe { _mm_loadu_si128(block.as_ptr().cast::<__m128i>()) };
+            // the mask is built from the most significant bit of each byte, which is
+            // only set for the bytes of non ascii characters
+            let mut found = if non_ascii {
+                block
+            } else {
+                _mm_setzero_si128()
+            };
+            for needle in needles {
+                let needle = _mm_cmpeq_epi8(block, _mm_set1_epi8(*needle as i8));
+                found = _mm_or_si128(found, needle);
+            }
+            let mask = _mm_movemask_epi8(found);
+            if mask != 0 {
+                return Some(index * BLOCK_SIZE + mask.trailing_zeros() as usize);
+            }
+        }
+        None
+    }
+}
+
+/// Returns the escaper of the text content for the given policy.
+pub(crate) fn text(escaping: Escaping) -> &'static Escaper {
+    match escaping {
+        Escaping::Minimal => &MINIMAL,
+        Escaping::Strict => &CONTENT,
+        Escaping::AsciiOnly => &ASCII_ONLY,
+    }
+}
+
+/// Writer escaping everything that goes through it before writing it to the
+/// inner writer.
+///
+/// The value is escaped one fragment at a time, as the [Display](std::fmt::Display)
+/// implementations write it, so it's never formatted to an intermediate [String].
+///
+/// ```rust
+/// use std::fmt::Write;
+///
+/// use another_html_builder::EscapingWriter;
+///
+/// let mut output = String::new();
+/// write!(EscapingWriter::value(&mut output), "{} > {}", "\"b\"", 1.5).unwrap();
+/// assert_eq!(output, "&quot;b&quot; &gt; 1.5");
+/// ```
+pub struct EscapingWriter<W> {
+    inner: W,
+    escaper: &'static Escaper,
+}
+
+impl<W> EscapingWriter<W> {
+    /// Escapes text content following the given policy, like
+    /// [EscapedText](crate::content::EscapedText).
+    pub fn text(inner: W, escaping: Escaping) -> Self {
+        Self {
+            inner,
+            escaper: text(escaping),
+        }
+    }
+
+    /// Escapes text content of xml documents, like
+    /// [EscapedXmlContent](crate::content::EscapedXmlContent).
+    pub fn xml_text(inner: W) -> Self {
+        Self {
+            inner,
+            escaper: &XML_CONTENT,
+        }
+    }
+
+    /// Escapes attribute values, like [EscapedValue](crate::attribute::EscapedValue).
+    pub fn value(inner: W) -> Self {
+        Self {
+            inner,
+            escaper: &VALUE,
+        }
+    }
+
+    pub(crate) fn with_escaper(inner: W, escaper: &'static Escaper) -> Self {
+        Self { inner, escaper }
+    }
+
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: std::fmt::Write> std::fmt::Write for EscapingWriter<W> {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        self.escaper.write(&mut self.inner, s)
+    }
+}
+
+/// Displays a [str] escaped with the given escaper, without going through an [EscapingWriter].
+pub(crate) struct EscapedStr<'a>(pub &'a str, pub &'static Escaper);
+
+impl std::fmt::Display for EscapedStr<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.1.write(f, self.0)
+    }
+}
+
+/// Displays a value through an [EscapingWriter].
+pub(crate) struct Escaped<T>(pub T, pub &'static Escaper);
+
+impl<T: std::fmt::Display> std::fmt::Display for Escaped<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use std::fmt::Write;
+
+        write!(EscapingWriter::with_escaper(f, self.1), "{}", self.0)
+    }
+}
+
+/// Characters escaped in the text content with the minimal policy.
+pub(crate) static MINIMAL: Escaper = Escaper::new(b"&<", &["&amp;", "&lt;"]);
+
+/// Characters escaped in the text content of html elements.
+pub(crate) static CONTENT: Escaper = Escaper::new(
+    b"&<>\"'/",
+    &["&amp;", "&lt;", "&gt;", "&quot;", "&#x27;", "&#x2F;"],
+);
+
+/// Characters escaped in the text content to only produce ascii.
+pub(crate) static ASCII_ONLY: Escaper = Escaper::new(
+    b"&<>\"'/",
+    &["&amp;", "&lt;", "&gt;", "&quot;", "&#x27;", "&#x2F;"],
+)
+.with_non_ascii(Reference::Hexadecimal);
+
+/// Characters escaped in the text content of xml elements.
+pub(crate) static XML_CONTENT: Escaper = Escaper::new(b"&<>", &["&amp;", "&lt;", "&gt;"]);
+
+/// Characters escaped in attribute values written between double quotes.
+pub(crate) static VALUE: Escaper = Escaper::new(b"&\"<>", &["&amp;", "&quot;", "&lt;", "&gt;"]);
+
+/// Non ascii characters of the attribute values with the ascii only policy, replaced
+/// once the value is already escaped.
+pub(crate) static VALUE_NON_ASCII: Escaper =
+    Escaper::new(b"", &[]).with_non_ascii(Reference::Decimal);
+
+#[cfg(test)]
+mod tests {
+    use super::{Escaper, Reference};
+
+    /// Escapes one character at a time, to compare with the block scanning.
+    fn reference(escaper: &Escaper, input: &str) -> String {
+        input
+            .chars()
+            .map(|c| match escaper.table.get(c as usize).copied().flatten() {
+                Some(replacement) => replacement.to_string(),
+                None if c.is_ascii() => c.to_string(),
+                None => match escaper.non_ascii {
+                    Some(Reference::Hexadecimal) => format!("&#x{:X};", c as u32),
+                    Some(Reference::Decimal) => format!("&#{};", c as u32),
+                    None => c.to_string(),
+                },
+            })
+            .collect()
+    }
+
+    fn escape(escaper: &Escaper, input: &str) -> String {
+        let mut output = String::new();
+        escaper.write(&mut output, input).unwrap();
+        output
+    }
+
+    #[test_case::test_case(&crate::escape::MINIMAL; "minimal")]
+    #[test_case::test_case(&crate::escape::CONTENT; "content")]
+    #[test_case::test_case(&crate::escape::ASCII_ONLY; "ascii only")]
+    #[test_case::test_case(&crate::escape::XML_CONTENT; "xml content")]
+    #[test_case::test_case(&crate::escape::VALUE; "value")]
+    #[test_case::test_case(&crate::escape::VALUE_NON_ASCII; "value non ascii")]
+    fn escaping_at_every_position(escaper: &Escaper) {
+        for length in 0..40 {
+            for position in 0..length {
+                for special in ['&', '<', '>', '"', '\'', '/'] {
+                    let mut input: Vec<char> = "héllo wörld, how are you doing?!"
+                        .chars()
+                        .cycle()
+                        .take(length)
+                        .collect();
+                    input[position] = special;
+                    let input: String = input.into_iter().collect();
+                    assert_eq!(escape(escaper, &input), reference(escaper, &input));
+                }
+            }
+        }
+    }
+
+    #[test_case::test_case("", ""; "empty")]
+    #[test_case::test_case("a very long text without anything to escape", "a very long text without anything to escape"; "without character to escape")]
+    #[test_case::test_case("<<<<<<<<<<<<<<<<<", "&lt;&lt;&lt;&lt;&lt;&lt;&lt;&lt;&lt;&lt;&lt;&lt;&lt;&lt;&lt;&lt;&lt;"; "with only characters to escape")]
+    #[test_case::test_case("ünïcödé ünïcödé ünïcödé & <ü>", "ünïcödé ünïcödé ünïcödé &amp; &lt;ü&gt;"; "with unicode")]
+    fn escaping_value(input: &str, expected: &str) {
+        assert_eq!(escape(&crate::escape::VALUE, input), expected);
+    }
+
+    #[test_case::test_case("café", "caf&#xE9;"; "with two bytes character")]
+    #[test_case::test_case("a→b", "a&#x2192;b"; "with three bytes character")]
+    #[test_case::test_case("🦀 <crab>", "&#x1F980; &lt;crab&gt;"; "with four bytes character")]
+    #[test_case::test_case("ünïcödé ünïcödé ünïcödé", "&#xFC;n&#xEF;c&#xF6;d&#xE9; &#xFC;n&#xEF;c&#xF6;d&#xE9; &#xFC;n&#xEF;c&#xF6;d&#xE9;"; "with long input")]
+    fn escaping_non_ascii(input: &str, expected: &str) {
+        assert_eq!(escape(&crate::escape::ASCII_ONLY, input), expected);
+    }
+
+    #[test]
+    fn escaping_writer() {
+        use std::fmt::Write;
+
+        use crate::content::Escaping;
+
+        let mut output = String::new();
+        let mut writer = super::EscapingWriter::text(&mut output, Escaping::AsciiOnly);
+        let character = 'é';
+        write!(writer, "{character}</{}", 1.5).unwrap();
+        writer.write_char('&').unwrap();
+        assert_eq!(output, "&#xE9;&lt;&#x2F;1.5&amp;");
+    }
+}
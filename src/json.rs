@@ -0,0 +1,121 @@
+//! Json related module. This contains a wrapper to embed serialized values in
+//! a `script` element.
+//!
+//! This module is only available with the `serde` feature.
+
+use std::io;
+
+use crate::error::Cause;
+use crate::prelude::{FmtWriter, WriterExt};
+
+/// Wrapper around a [Serialize](serde::Serialize) value that will be written as
+/// json that can safely be embedded in a `script` element.
+///
+/// On top of the regular json escaping, this implementation will transform:
+/// - `<` to `\u003c`
+/// - `>` to `\u003e`
+/// - `&` to `\u0026`
+/// - `U+2028` to `\u2028`
+/// - `U+2029` to `\u2029`
+///
+/// Those characters can only be found in json strings, where the escaped form is
+/// equivalent, so the value is parsed the same way.
+///
+/// Formatting fails when the value cannot be serialized, like a map with non string keys.
+/// [Buffer::try_json](crate::Buffer::try_json) returns the [serde_json::Error] instead.
+pub struct EscapedJson<'a, T: ?Sized>(pub &'a T);
+
+impl<T: serde::Serialize + ?Sized> std::fmt::Display for EscapedJson<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write(&mut FmtWriter(f), self.0).map_err(|_| std::fmt::Error)
+    }
+}
+
+/// Serializes the value to the writer, escaping it like [EscapedJson].
+///
+/// What has been serialized before an error stays in the writer.
+pub(crate) fn write<W, T>(writer: &mut W, value: &T) -> Result<(), Cause<W::Error>>
+where
+    W: WriterExt,
+    T: serde::Serialize + ?Sized,
+{
+    let mut adapter = WriterAdapter {
+        inner: writer,
+        error: None,
+    };
+    let mut serializer = serde_json::Serializer::with_formatter(&mut adapter, ScriptFormatter);
+    value
+        .serialize(&mut serializer)
+        .map_err(|err| adapter.error.take().map_or(Cause::Json(err), Cause::Writer))
+}
+
+/// Adapter allowing to write the output of [serde_json] to a [WriterExt] and keeping
+/// the error of the writer.
+struct WriterAdapter<'a, W: WriterExt> {
+    inner: &'a mut W,
+    error: Option<W::Error>,
+}
+
+impl<W: WriterExt> io::Write for WriterAdapter<'_, W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        // serde_json only writes complete utf-8 sequences
+        let value = std::str::from_utf8(buf).map_err(io::Error::other)?;
+        if let Err(err) = self.inner.write_str(value) {
+            self.error = Some(err);
+            return Err(io::Error::other("writer error"));
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Compact json formatter escaping the characters that are unsafe in a `script` element.
+struct ScriptFormatter;
+
+impl serde_json::ser::Formatter for ScriptFormatter {
+    fn write_string_fragment<W>(&mut self, writer: &mut W, fragment: &str) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        let mut start: usize = 0;
+        for (index, character) in fragment.char_indices() {
+            let escaped = match character {
+                '<' => "\\u003c",
+                '>' => "\\u003e",
+                '&' => "\\u0026",
+                '\u{2028}' => "\\u2028",
+                '\u{2029}' => "\\u2029",
+                _ => continue,
+            };
+            writer.write_all(&fragment.as_bytes()[start..index])?;
+            writer.write_all(escaped.as_bytes())?;
+            start = index + character.len_utf8();
+        }
+        writer.write_all(&fragment.as_bytes()[start..])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    #[test_case::test_case("hello world", "\"hello world\""; "without character to escape")]
+    #[test_case::test_case("</script><b>", "\"\\u003c/script\\u003e\\u003cb\\u003e\""; "with closing tag")]
+    #[test_case::test_case("a & \"b\"", "\"a \\u0026 \\\"b\\\"\""; "with ampersand and quotes")]
+    #[test_case::test_case("a\u{2028}b\u{2029}c", "\"a\\u2028b\\u2029c\""; "with line separators")]
+    fn escaping_json(input: &str, expected: &str) {
+        assert_eq!(format!("{}", super::EscapedJson(input)), expected);
+    }
+
+    #[test]
+    fn escaping_keys() {
+        let value = BTreeMap::from([("<!--", vec![1, 2])]);
+        assert_eq!(
+            format!("{}", super::EscapedJson(&value)),
+            "{\"\\u003c!--\":[1,2]}"
+        );
+    }
+}
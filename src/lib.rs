@@ -9,6 +9,7 @@
 //! ```rust
 //! use another_html_builder::attribute::AttributeValue;
 //! use another_html_builder::prelude::WriterExt;
+//! use another_html_builder::render::Render;
 //! use another_html_builder::{Body, Buffer};
 //!
 //! enum Lang {
@@ -37,8 +38,8 @@
 //!     }
 //! }
 //!
-//! impl Head {
-//!     fn render<'a, W: WriterExt>(&self, buf: Buffer<W, Body<'a>>) -> Buffer<W, Body<'a>> {
+//! impl<W: WriterExt> Render<W> for Head {
+//!     fn render<'a>(&self, buf: Buffer<W, Body<'a>>) -> Buffer<W, Body<'a>> {
 //!         buf.node("head")
 //!             .content(|buf| buf.node("title").content(|buf| buf.text(self.title)))
 //!     }
@@ -49,7 +50,7 @@
 //!     .doctype()
 //!     .node("html")
 //!     .attr(("lang", Lang::Fr))
-//!     .content(|buf| head.render(buf))
+//!     .content(|buf| buf.render(&head))
 //!     .into_inner();
 //! assert_eq!(
 //!     html,
@@ -57,34 +58,148 @@
 //! );
 //! ```
 pub mod attribute;
+pub mod attrs;
+pub mod chunk;
 pub mod content;
+pub mod dialect;
+pub mod elements;
+mod error;
+mod escape;
+#[cfg(feature = "serde")]
+pub mod json;
+pub mod name;
 pub mod prelude;
+pub mod pretty;
+pub mod render;
 
-use crate::prelude::{FmtWriter, IoWriter, WriterExt};
+/// Writes html with a JSX like syntax.
+///
+/// The macro expands to a closure taking a [Buffer] and returning it, calling the
+/// same methods that would be written by hand, so nothing gets allocated.
+/// It can be given to [Buffer::content] or wrapped with [render::from_fn] to be
+/// used as a component.
+///
+/// The following syntax is supported:
+/// - `<div class="a" data-id={id} hidden {attribute}>children</div>`, where `{attribute}`
+///   is anything accepted by [Buffer::attr]
+/// - `<br />` to close an element without content
+/// - `"text"`, written with [Buffer::text]
+/// - `{expression}`, any [Display](std::fmt::Display) value written with [Buffer::text_display]
+/// - `@{component}`, written with [Buffer::render]
+/// - `<Component field={value} />`, creating the component struct and rendering it
+/// - `if condition { ... } else if other { ... } else { ... }`, including `if let`
+/// - `for pattern in iterator { ... }`
+/// - `match value { pattern => { ... } }`
+///
+/// A closing tag that doesn't match its opening tag is a compile error.
+///
+/// ```rust
+/// use another_html_builder::html;
+/// use another_html_builder::render::from_fn;
+///
+/// struct Title<'a> {
+///     text: &'a str,
+/// }
+///
+/// impl<W: another_html_builder::prelude::WriterExt> another_html_builder::render::Render<W>
+///     for Title<'_>
+/// {
+///     fn render<'a>(
+///         &self,
+///         buf: another_html_builder::Buffer<W, another_html_builder::Body<'a>>,
+///     ) -> another_html_builder::Buffer<W, another_html_builder::Body<'a>> {
+///         buf.node("h1").content(|buf| buf.text(self.text))
+///     }
+/// }
+///
+/// let items = ["a", "b"];
+/// let user: Option<&str> = Some("Alice");
+/// let page = from_fn(html! {
+///     <Title text="Hello" />
+///     if let Some(name) = user {
+///         <p class="user">"Welcome " {name}</p>
+///     } else {
+///         <a href="/login">"Login"</a>
+///     }
+///     <ul>
+///         for item in items.iter() {
+///             <li data-item={*item}>{item}</li>
+///         }
+///     </ul>
+///     <br />
+/// });
+/// let html = another_html_builder::Buffer::default()
+///     .render(&page)
+///     .into_inner();
+/// assert_eq!(
+///     html,
+///     "<h1>Hello</h1><p class=\"user\">Welcome Alice</p><ul><li data-item=\"a\">a</li><li data-item=\"b\">b</li></ul><br>"
+/// );
+/// ```
+///
+/// ```compile_fail
+/// use another_html_builder::html;
+///
+/// let html = another_html_builder::Buffer::default()
+///     .node("div")
+///     .content(html! { <p>"Hello"</div> })
+///     .into_inner();
+/// ```
+#[cfg(feature = "macros")]
+pub use another_html_builder_macros::html;
+
+use crate::content::Escaping;
+use crate::dialect::{Dialect, EmptyElementEnd};
+pub use crate::error::{Cause, Error, Operation};
+pub use crate::escape::EscapingWriter;
+use crate::prelude::{AsyncWriterExt, FmtWriter, IoWriter, Latched, WriterExt};
+use crate::pretty::{Layout, Pretty};
 
 /// Representation of the inside of an element or the root level.
 ///
 /// This component is made for the [Buffer] to be aware of where it is
 /// and provide adequat functions.
-#[derive(Debug)]
+///
+/// The parent of an element lives on the stack of [Buffer::content], so nesting
+/// elements doesn't allocate.
+#[derive(Clone, Copy, Debug)]
 pub enum Body<'a> {
     /// This represents the root of the DOM. It has not name nor parents.
     Root,
     /// This represents any element with a name.
+    ///
+    /// The depth and the formatting are computed from the parent when the element
+    /// is created, so they don't need to walk through the parents.
     Element {
         name: &'a str,
-        parent: Box<Body<'a>>,
+        parent: &'a Body<'a>,
+        /// Number of elements containing the current position.
+        depth: usize,
+        /// Whether line breaks can be added inside the element without changing the rendering.
+        formatted: bool,
     },
 }
 
-impl Body<'_> {
+impl<'a> Body<'a> {
+    /// Creates the body of an element, inside the given parent.
+    fn element(name: &'a str, parent: &'a Body<'a>) -> Self {
+        Self::Element {
+            name,
+            parent,
+            depth: parent.depth() + 1,
+            formatted: parent.is_formatted()
+                && !pretty::is_inline_element(name)
+                && !pretty::is_whitespace_sensitive_element(name),
+        }
+    }
+
     /// Generates the path of the current element.
     ///
     /// Note: this will not provid a valide CSS path
     pub fn path(&self) -> String {
         match self {
             Self::Root => String::from("$"),
-            Self::Element { name, parent } => {
+            Self::Element { name, parent, .. } => {
                 let mut parent_path = parent.path();
                 parent_path.push_str(" > ");
                 parent_path.push_str(name);
@@ -92,13 +207,75 @@ impl Body<'_> {
             }
         }
     }
+
+    /// Number of elements containing the current position.
+    fn depth(&self) -> usize {
+        match self {
+            Self::Root => 0,
+            Self::Element { depth, .. } => *depth,
+        }
+    }
+
+    /// Checks if line breaks can be added at the current position without changing the rendering.
+    fn is_formatted(&self) -> bool {
+        match self {
+            Self::Root => true,
+            Self::Element { formatted, .. } => *formatted,
+        }
+    }
+}
+
+/// Value that can be one of two types, used with [Buffer::either].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
+
+impl<L, R> From<Result<L, R>> for Either<L, R> {
+    fn from(value: Result<L, R>) -> Self {
+        match value {
+            Ok(inner) => Self::Left(inner),
+            Err(inner) => Self::Right(inner),
+        }
+    }
 }
 
 /// Representation of an element
-#[derive(Debug)]
-pub struct Element<'a> {
+///
+/// The name of the element has its own lifetime, so it can be borrowed from a value
+/// living shorter than the parent, like a tag name computed in [Buffer::content].
+#[derive(Clone, Copy, Debug)]
+pub struct Element<'a, 't> {
     parent: Body<'a>,
-    name: &'a str,
+    name: &'t str,
+}
+
+impl Element<'_, '_> {
+    /// Generates the path of the current element, including its name.
+    pub fn path(&self) -> String {
+        let mut path = self.parent.path();
+        path.push_str(" > ");
+        path.push_str(self.name);
+        path
+    }
+}
+
+/// Position of the [Buffer] in the document, used to locate the errors.
+trait Position {
+    fn path(&self) -> String;
+}
+
+impl Position for Body<'_> {
+    fn path(&self) -> String {
+        Body::path(self)
+    }
+}
+
+impl Position for Element<'_, '_> {
+    fn path(&self) -> String {
+        Element::path(self)
+    }
 }
 
 /// Wrapper arround a writer element.
@@ -106,6 +283,72 @@ pub struct Element<'a> {
 pub struct Buffer<W, C> {
     inner: W,
     current: C,
+    dialect: Dialect,
+    pretty: Option<Pretty>,
+    escaping: Option<Escaping>,
+    layout: Layout,
+}
+
+impl<W> Buffer<W, Body<'static>> {
+    fn root(inner: W) -> Self {
+        Self {
+            inner,
+            current: Body::Root,
+            dialect: Dialect::default(),
+            pretty: None,
+            escaping: None,
+            layout: Layout::default(),
+        }
+    }
+}
+
+impl<W, C> Buffer<W, C> {
+    /// Moves the buffer to another level of the DOM.
+    #[inline]
+    fn map_current<N, F: FnOnce(C) -> N>(self, func: F) -> Buffer<W, N> {
+        Buffer {
+            inner: self.inner,
+            current: func(self.current),
+            dialect: self.dialect,
+            pretty: self.pretty,
+            escaping: self.escaping,
+            layout: self.layout,
+        }
+    }
+
+    /// Returns the escaper of the text content, depending on the policy and the dialect.
+    fn text_escaper(&self) -> &'static escape::Escaper {
+        match (self.escaping, self.dialect) {
+            (Some(escaping), _) => escape::text(escaping),
+            (None, Dialect::Xml) => &escape::XML_CONTENT,
+            (None, Dialect::Html5 | Dialect::Xhtml) => &escape::CONTENT,
+        }
+    }
+}
+
+impl<W: WriterExt, C> Buffer<W, C> {
+    /// Runs a writing operation, locating the error at the current position.
+    #[inline]
+    fn attempt<F>(&mut self, operation: Operation, func: F) -> Result<(), Error<W::Error>>
+    where
+        C: Position,
+        F: FnOnce(&mut Self) -> Result<(), W::Error>,
+    {
+        match func(self) {
+            Ok(()) => Ok(()),
+            Err(source) => self.fail(operation, Cause::Writer(source)),
+        }
+    }
+
+    /// Locates the error at the current position and gives it to the writer, which
+    /// returns it unless it's latched.
+    fn fail(&mut self, operation: Operation, cause: Cause<W::Error>) -> Result<(), Error<W::Error>>
+    where
+        C: Position,
+    {
+        let error = Error::new(cause, self.current.path(), operation);
+        self.inner.latch(error)
+    }
 }
 
 impl Default for Buffer<FmtWriter<String>, Body<'static>> {
@@ -116,19 +359,13 @@ impl Default for Buffer<FmtWriter<String>, Body<'static>> {
 
 impl<W: std::fmt::Write> From<W> for Buffer<FmtWriter<W>, Body<'static>> {
     fn from(buffer: W) -> Self {
-        Self {
-            inner: FmtWriter(buffer),
-            current: Body::Root,
-        }
+        Self::root(FmtWriter(buffer))
     }
 }
 
 impl<W: std::io::Write> From<W> for Buffer<IoWriter<W>, Body<'static>> {
     fn from(value: W) -> Self {
-        Self {
-            inner: IoWriter(value),
-            current: Body::Root,
-        }
+        Self::root(IoWriter(value))
     }
 }
 
@@ -144,22 +381,276 @@ impl<W> Buffer<IoWriter<W>, Body<'_>> {
     }
 }
 
+#[cfg(feature = "tokio")]
+impl<W> From<prelude::TokioWriter<W>> for Buffer<prelude::TokioWriter<W>, Body<'static>> {
+    fn from(value: prelude::TokioWriter<W>) -> Self {
+        Self::root(value)
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<W> Buffer<prelude::TokioWriter<W>, Body<'_>> {
+    /// Returns the underlying writer, dropping what hasn't been sent with [Buffer::flush_async].
+    pub fn into_inner(self) -> W {
+        self.inner.into_inner()
+    }
+}
+
+#[cfg(feature = "futures")]
+impl<W> From<prelude::FuturesWriter<W>> for Buffer<prelude::FuturesWriter<W>, Body<'static>> {
+    fn from(value: prelude::FuturesWriter<W>) -> Self {
+        Self::root(value)
+    }
+}
+
+#[cfg(feature = "futures")]
+impl<W> Buffer<prelude::FuturesWriter<W>, Body<'_>> {
+    /// Returns the underlying writer, dropping what hasn't been sent with [Buffer::flush_async].
+    pub fn into_inner(self) -> W {
+        self.inner.into_inner()
+    }
+}
+
+impl<W: AsyncWriterExt> Buffer<W, Body<'_>> {
+    /// Sends what has been written so far to the underlying asynchronous writer.
+    ///
+    /// See [AsyncWriterExt] for the available writers.
+    pub async fn flush_async(mut self) -> Result<Self, Error<W::Error>> {
+        if let Err(source) = self.inner.flush_async().await {
+            self.fail(Operation::Flush, Cause::Writer(source))?;
+        }
+        Ok(self)
+    }
+}
+
+impl<'a, W: WriterExt> Buffer<W, Body<'a>> {
+    /// Keeps the first error instead of panicking.
+    ///
+    /// Everything written after an error is ignored and the error is returned by
+    /// [Buffer::finish], so the infallible methods can be used with writers that can fail.
+    /// This covers the errors of the writer as well as the content rejected by the buffer,
+    /// like an invalid tag name or a `NUL` character given to [Buffer::script].
+    ///
+    /// ```rust
+    /// let (sender, receiver) = std::sync::mpsc::channel();
+    /// drop(receiver);
+    /// let writer = another_html_builder::chunk::ChunkedWriter::new(sender, 4);
+    /// let result = another_html_builder::Buffer::from(writer)
+    ///     .latched()
+    ///     .node("p")
+    ///     .content(|buf| buf.text("Hello World!"))
+    ///     .finish();
+    /// assert!(result.is_err());
+    /// ```
+    pub fn latched(self) -> Buffer<Latched<W>, Body<'a>> {
+        Buffer {
+            inner: Latched::new(self.inner),
+            current: self.current,
+            dialect: self.dialect,
+            pretty: self.pretty,
+            escaping: self.escaping,
+            layout: self.layout,
+        }
+    }
+}
+
+impl<'a, W: WriterExt> Buffer<Latched<W>, Body<'a>> {
+    /// Returns the buffer with the underlying writer, or the first error that happened.
+    ///
+    /// ```rust
+    /// let html = another_html_builder::Buffer::default()
+    ///     .latched()
+    ///     .node("p")
+    ///     .content(|buf| buf.text("Hello"))
+    ///     .finish()
+    ///     .unwrap()
+    ///     .into_inner();
+    /// assert_eq!(html, "<p>Hello</p>");
+    /// ```
+    pub fn finish(self) -> Result<Buffer<W, Body<'a>>, Error<W::Error>> {
+        Ok(Buffer {
+            inner: self.inner.into_result()?,
+            current: self.current,
+            dialect: self.dialect,
+            pretty: self.pretty,
+            escaping: self.escaping,
+            layout: self.layout,
+        })
+    }
+}
+
 impl Buffer<FmtWriter<String>, Body<'_>> {
     pub fn inner(&self) -> &str {
         self.inner.0.as_str()
     }
 }
 
-impl<W: WriterExt> Buffer<W, Body<'_>> {
-    /// Appends the html doctype to the buffer
-    pub fn doctype(mut self) -> Self {
-        self.inner.write_str("<!DOCTYPE html>").unwrap();
+impl<W> Buffer<W, Body<'_>> {
+    /// Changes the way the elements are serialized.
+    ///
+    /// By default, the buffer produces html5.
+    ///
+    /// ```rust
+    /// use another_html_builder::dialect::Dialect;
+    ///
+    /// let html = another_html_builder::Buffer::default()
+    ///     .with_dialect(Dialect::Xhtml)
+    ///     .node("br")
+    ///     .close()
+    ///     .into_inner();
+    /// assert_eq!(html, "<br />");
+    /// ```
+    pub fn with_dialect(mut self, dialect: Dialect) -> Self {
+        self.dialect = dialect;
+        self
+    }
+
+    /// Changes the characters escaped in the text content.
+    ///
+    /// By default, the escaping depends on the [Dialect], see [Buffer::text].
+    /// The attribute values are always escaped the same way, except with
+    /// [Escaping::AsciiOnly](content::Escaping::AsciiOnly) that also writes their
+    /// non ascii characters as numeric character references.
+    ///
+    /// ```rust
+    /// use another_html_builder::content::Escaping;
+    ///
+    /// let html = another_html_builder::Buffer::default()
+    ///     .with_escaping(Escaping::Minimal)
+    ///     .node("p")
+    ///     .content(|buf| buf.text("Tom & Jerry's </p>"))
+    ///     .into_inner();
+    /// assert_eq!(html, "<p>Tom &amp; Jerry's &lt;/p></p>");
+    /// ```
+    pub fn with_escaping(mut self, escaping: Escaping) -> Self {
+        self.escaping = Some(escaping);
+        self
+    }
+
+    /// Enables pretty printing with the given options.
+    ///
+    /// Block elements are written on their own line and indented depending
+    /// on their depth. Inline and whitespace sensitive elements are left untouched.
+    ///
+    /// ```rust
+    /// use another_html_builder::pretty::Pretty;
+    ///
+    /// let html = another_html_builder::Buffer::default()
+    ///     .pretty(Pretty::default())
+    ///     .doctype()
+    ///     .node("html")
+    ///     .content(|buf| {
+    ///         buf.node("body").content(|buf| {
+    ///             buf.node("p")
+    ///                 .content(|buf| buf.text("Hello"))
+    ///                 .node("pre")
+    ///                 .content(|buf| buf.node("code").content(|buf| buf.text("World")))
+    ///         })
+    ///     })
+    ///     .into_inner();
+    /// assert_eq!(
+    ///     html,
+    ///     r#"<!DOCTYPE html>
+    /// <html>
+    ///   <body>
+    ///     <p>Hello</p>
+    ///     <pre><code>World</code></pre>
+    ///   </body>
+    /// </html>"#
+    /// );
+    /// ```
+    pub fn pretty(mut self, options: Pretty) -> Self {
+        self.pretty = Some(options);
         self
     }
+}
+
+impl<W: WriterExt> Buffer<W, Body<'_>> {
+    /// Appends the doctype to the buffer
+    ///
+    /// Depending on the [Dialect] of the buffer, this will write the html doctype,
+    /// the xml declaration followed by the html doctype or only the xml declaration.
+    ///
+    /// ```rust
+    /// use another_html_builder::dialect::Dialect;
+    ///
+    /// let html = another_html_builder::Buffer::default()
+    ///     .doctype()
+    ///     .into_inner();
+    /// assert_eq!(html, "<!DOCTYPE html>");
+    ///
+    /// let xml = another_html_builder::Buffer::default()
+    ///     .with_dialect(Dialect::Xml)
+    ///     .doctype()
+    ///     .into_inner();
+    /// assert_eq!(xml, "<?xml version=\"1.0\" encoding=\"UTF-8\"?>");
+    /// ```
+    pub fn doctype(self) -> Self {
+        self.try_doctype().unwrap()
+    }
+
+    /// Tries to append the doctype to the buffer
+    pub fn try_doctype(mut self) -> Result<Self, Error<W::Error>> {
+        self.attempt(Operation::Doctype, |buf| {
+            buf.inner.write_str(buf.dialect.doctype())
+        })?;
+        self.layout.started = true;
+        Ok(self)
+    }
+
+    /// Appends a doctype with the given definition to the buffer
+    ///
+    /// The definition is not escaped.
+    ///
+    /// ```rust
+    /// use another_html_builder::dialect::Dialect;
+    ///
+    /// let xml = another_html_builder::Buffer::default()
+    ///     .with_dialect(Dialect::Xml)
+    ///     .doctype()
+    ///     .custom_doctype("note SYSTEM \"note.dtd\"")
+    ///     .into_inner();
+    /// assert_eq!(
+    ///     xml,
+    ///     "<?xml version=\"1.0\" encoding=\"UTF-8\"?><!DOCTYPE note SYSTEM \"note.dtd\">"
+    /// );
+    /// ```
+    pub fn custom_doctype(self, definition: &str) -> Self {
+        self.try_custom_doctype(definition).unwrap()
+    }
+
+    pub fn try_custom_doctype(mut self, definition: &str) -> Result<Self, Error<W::Error>> {
+        self.attempt(Operation::Doctype, |buf| {
+            buf.inner.write_str("<!DOCTYPE ")?;
+            buf.inner.write_str(definition)?;
+            buf.inner.write_char('>')
+        })?;
+        Ok(self)
+    }
+
+    /// Flushes the underlying writer, to send what has been written so far.
+    ///
+    /// This allows to send the head of the page, or the content above the fold,
+    /// while the rest of the page is still being built.
+    ///
+    /// ```rust
+    /// use std::io::BufWriter;
+    ///
+    /// let buf = another_html_builder::Buffer::from(BufWriter::new(Vec::new()))
+    ///     .node("head")
+    ///     .content(|buf| buf.node("title").content(|buf| buf.text("Hello")))
+    ///     .flush();
+    /// assert_eq!(
+    ///     buf.into_inner().get_ref(),
+    ///     b"<head><title>Hello</title></head>"
+    /// );
+    /// ```
+    pub fn flush(self) -> Self {
+        self.try_flush().unwrap()
+    }
 
-    /// Tries to append the html doctype to the buffer
-    pub fn try_doctype(mut self) -> Result<Self, W::Error> {
-        self.inner.write_str("<!DOCTYPE html>")?;
+    pub fn try_flush(mut self) -> Result<Self, Error<W::Error>> {
+        self.attempt(Operation::Flush, |buf| buf.inner.flush())?;
         Ok(self)
     }
 }
@@ -187,9 +678,13 @@ impl<'a, W: WriterExt> Buffer<W, Body<'a>> {
         }
     }
 
-    pub fn try_cond<F>(self, condition: bool, children: F) -> Result<Buffer<W, Body<'a>>, W::Error>
+    pub fn try_cond<F>(
+        self,
+        condition: bool,
+        children: F,
+    ) -> Result<Buffer<W, Body<'a>>, Error<W::Error>>
     where
-        F: FnOnce(Buffer<W, Body>) -> Result<Buffer<W, Body>, W::Error>,
+        F: FnOnce(Buffer<W, Body>) -> Result<Buffer<W, Body>, Error<W::Error>>,
     {
         if condition {
             children(self)
@@ -225,9 +720,9 @@ impl<'a, W: WriterExt> Buffer<W, Body<'a>> {
         self,
         value: Option<V>,
         children: F,
-    ) -> Result<Buffer<W, Body<'a>>, W::Error>
+    ) -> Result<Buffer<W, Body<'a>>, Error<W::Error>>
     where
-        F: FnOnce(Buffer<W, Body>, V) -> Result<Buffer<W, Body>, W::Error>,
+        F: FnOnce(Buffer<W, Body>, V) -> Result<Buffer<W, Body>, Error<W::Error>>,
     {
         if let Some(inner) = value {
             children(self, inner)
@@ -236,6 +731,258 @@ impl<'a, W: WriterExt> Buffer<W, Body<'a>> {
         }
     }
 
+    /// Conditionally apply some children to an element, or some other children
+    ///
+    /// ```rust
+    /// let is_error = false;
+    /// let html = another_html_builder::Buffer::default()
+    ///     .cond_else(
+    ///         is_error,
+    ///         |buf| buf.text("ERROR!"),
+    ///         |buf| buf.text("Everything is fine"),
+    ///     )
+    ///     .into_inner();
+    /// assert_eq!(html, "Everything is fine");
+    /// ```
+    pub fn cond_else<F, G>(self, condition: bool, children: F, otherwise: G) -> Buffer<W, Body<'a>>
+    where
+        F: FnOnce(Buffer<W, Body>) -> Buffer<W, Body>,
+        G: FnOnce(Buffer<W, Body>) -> Buffer<W, Body>,
+    {
+        if condition {
+            children(self)
+        } else {
+            otherwise(self)
+        }
+    }
+
+    pub fn try_cond_else<F, G>(
+        self,
+        condition: bool,
+        children: F,
+        otherwise: G,
+    ) -> Result<Buffer<W, Body<'a>>, Error<W::Error>>
+    where
+        F: FnOnce(Buffer<W, Body>) -> Result<Buffer<W, Body>, Error<W::Error>>,
+        G: FnOnce(Buffer<W, Body>) -> Result<Buffer<W, Body>, Error<W::Error>>,
+    {
+        if condition {
+            children(self)
+        } else {
+            otherwise(self)
+        }
+    }
+
+    /// Apply some children to an element depending on an optional, or some other children
+    ///
+    /// ```rust
+    /// let user: Option<&str> = None;
+    /// let html = another_html_builder::Buffer::default()
+    ///     .optional_else(
+    ///         user,
+    ///         |buf, name| buf.text("Hello ").text(name),
+    ///         |buf| buf.text("Please sign in"),
+    ///     )
+    ///     .into_inner();
+    /// assert_eq!(html, "Please sign in");
+    /// ```
+    pub fn optional_else<V, F, G>(
+        self,
+        value: Option<V>,
+        children: F,
+        otherwise: G,
+    ) -> Buffer<W, Body<'a>>
+    where
+        F: FnOnce(Buffer<W, Body>, V) -> Buffer<W, Body>,
+        G: FnOnce(Buffer<W, Body>) -> Buffer<W, Body>,
+    {
+        if let Some(inner) = value {
+            children(self, inner)
+        } else {
+            otherwise(self)
+        }
+    }
+
+    pub fn try_optional_else<V, F, G>(
+        self,
+        value: Option<V>,
+        children: F,
+        otherwise: G,
+    ) -> Result<Buffer<W, Body<'a>>, Error<W::Error>>
+    where
+        F: FnOnce(Buffer<W, Body>, V) -> Result<Buffer<W, Body>, Error<W::Error>>,
+        G: FnOnce(Buffer<W, Body>) -> Result<Buffer<W, Body>, Error<W::Error>>,
+    {
+        if let Some(inner) = value {
+            children(self, inner)
+        } else {
+            otherwise(self)
+        }
+    }
+
+    /// Apply some children to an element depending on the side of an [Either]
+    ///
+    /// ```rust
+    /// use another_html_builder::Either;
+    ///
+    /// let result: Result<u32, &str> = Err("not found");
+    /// let html = another_html_builder::Buffer::default()
+    ///     .either(
+    ///         Either::from(result),
+    ///         |buf, count| buf.text("Found: ").raw(count),
+    ///         |buf, error| buf.text("Error: ").text(error),
+    ///     )
+    ///     .into_inner();
+    /// assert_eq!(html, "Error: not found");
+    /// ```
+    pub fn either<L, R, F, G>(self, value: Either<L, R>, left: F, right: G) -> Buffer<W, Body<'a>>
+    where
+        F: FnOnce(Buffer<W, Body>, L) -> Buffer<W, Body>,
+        G: FnOnce(Buffer<W, Body>, R) -> Buffer<W, Body>,
+    {
+        match value {
+            Either::Left(inner) => left(self, inner),
+            Either::Right(inner) => right(self, inner),
+        }
+    }
+
+    pub fn try_either<L, R, F, G>(
+        self,
+        value: Either<L, R>,
+        left: F,
+        right: G,
+    ) -> Result<Buffer<W, Body<'a>>, Error<W::Error>>
+    where
+        F: FnOnce(Buffer<W, Body>, L) -> Result<Buffer<W, Body>, Error<W::Error>>,
+        G: FnOnce(Buffer<W, Body>, R) -> Result<Buffer<W, Body>, Error<W::Error>>,
+    {
+        match value {
+            Either::Left(inner) => left(self, inner),
+            Either::Right(inner) => right(self, inner),
+        }
+    }
+
+    /// Apply some children to an element for each item of an iterator
+    ///
+    /// The callback receives the index of the item.
+    ///
+    /// ```rust
+    /// let html = another_html_builder::Buffer::default()
+    ///     .node("ol")
+    ///     .content(|buf| {
+    ///         buf.iter(["a", "b"], |buf, index, item| {
+    ///             buf.node("li")
+    ///                 .attr(("data-index", index))
+    ///                 .content(|buf| buf.text(item))
+    ///         })
+    ///     })
+    ///     .into_inner();
+    /// assert_eq!(
+    ///     html,
+    ///     "<ol><li data-index=\"0\">a</li><li data-index=\"1\">b</li></ol>"
+    /// );
+    /// ```
+    pub fn iter<I, F>(self, items: I, mut children: F) -> Buffer<W, Body<'a>>
+    where
+        I: IntoIterator,
+        F: FnMut(Buffer<W, Body>, usize, I::Item) -> Buffer<W, Body>,
+    {
+        items
+            .into_iter()
+            .enumerate()
+            .fold(self, |buf, (index, item)| children(buf, index, item))
+    }
+
+    pub fn try_iter<I, F>(
+        self,
+        items: I,
+        mut children: F,
+    ) -> Result<Buffer<W, Body<'a>>, Error<W::Error>>
+    where
+        I: IntoIterator,
+        F: FnMut(Buffer<W, Body>, usize, I::Item) -> Result<Buffer<W, Body>, Error<W::Error>>,
+    {
+        items
+            .into_iter()
+            .enumerate()
+            .try_fold(self, |buf, (index, item)| children(buf, index, item))
+    }
+
+    /// Apply some children to an element for each item of an iterator, with a separator between them
+    ///
+    /// ```rust
+    /// let html = another_html_builder::Buffer::default()
+    ///     .node("p")
+    ///     .content(|buf| {
+    ///         buf.join(
+    ///             ["a", "b", "c"],
+    ///             |buf| buf.text(", "),
+    ///             |buf, item| buf.node("b").content(|buf| buf.text(item)),
+    ///         )
+    ///     })
+    ///     .into_inner();
+    /// assert_eq!(html, "<p><b>a</b>, <b>b</b>, <b>c</b></p>");
+    /// ```
+    pub fn join<I, S, F>(self, items: I, mut separator: S, mut children: F) -> Buffer<W, Body<'a>>
+    where
+        I: IntoIterator,
+        S: FnMut(Buffer<W, Body>) -> Buffer<W, Body>,
+        F: FnMut(Buffer<W, Body>, I::Item) -> Buffer<W, Body>,
+    {
+        items
+            .into_iter()
+            .enumerate()
+            .fold(self, |buf, (index, item)| {
+                let buf = if index > 0 { separator(buf) } else { buf };
+                children(buf, item)
+            })
+    }
+
+    pub fn try_join<I, S, F>(
+        self,
+        items: I,
+        mut separator: S,
+        mut children: F,
+    ) -> Result<Buffer<W, Body<'a>>, Error<W::Error>>
+    where
+        I: IntoIterator,
+        S: FnMut(Buffer<W, Body>) -> Result<Buffer<W, Body>, Error<W::Error>>,
+        F: FnMut(Buffer<W, Body>, I::Item) -> Result<Buffer<W, Body>, Error<W::Error>>,
+    {
+        items
+            .into_iter()
+            .enumerate()
+            .try_fold(self, |buf, (index, item)| {
+                let buf = if index > 0 { separator(buf)? } else { buf };
+                children(buf, item)
+            })
+    }
+
+    /// Writes a component implementing [Render](render::Render)
+    ///
+    /// ```rust
+    /// use another_html_builder::render::from_fn;
+    ///
+    /// let separator = from_fn(|buf| buf.node("hr").close());
+    /// let html = another_html_builder::Buffer::default()
+    ///     .render(&separator)
+    ///     .render(&Some(&separator))
+    ///     .render(&None::<()>.map(|_| &separator))
+    ///     .into_inner();
+    /// assert_eq!(html, "<hr><hr>");
+    /// ```
+    pub fn render<R: render::Render<W> + ?Sized>(self, component: &R) -> Buffer<W, Body<'a>> {
+        component.render(self)
+    }
+
+    /// Writes a component implementing [TryRender](render::TryRender)
+    pub fn try_render<R: render::TryRender<W> + ?Sized>(
+        self,
+        component: &R,
+    ) -> Result<Buffer<W, Body<'a>>, Error<W::Error>> {
+        component.try_render(self)
+    }
+
     /// Starts a new node in the buffer
     ///
     /// After calling this function, the buffer will only allow to add attributes,
@@ -247,7 +994,7 @@ impl<'a, W: WriterExt> Buffer<W, Body<'a>> {
     ///     .attr(("foo", "bar"))
     ///     .close()
     ///     .into_inner();
-    /// assert_eq!(html, "<p foo=\"bar\" />");
+    /// assert_eq!(html, "<p foo=\"bar\"></p>");
     /// ```
     ///
     /// ```rust
@@ -257,64 +1004,244 @@ impl<'a, W: WriterExt> Buffer<W, Body<'a>> {
     ///     .into_inner();
     /// assert_eq!(html, "<p>hello</p>");
     /// ```
-    pub fn node(mut self, tag: &'a str) -> Buffer<W, Element<'a>> {
-        self.inner.write_char('<').unwrap();
-        self.inner.write_str(tag).unwrap();
-        Buffer {
-            inner: self.inner,
-            current: Element {
-                name: tag,
-                parent: self.current,
-            },
-        }
+    ///
+    /// The tag name only needs to live until the element is closed, so it can be computed
+    /// while writing.
+    ///
+    /// ```rust
+    /// let level = 2;
+    /// let html = another_html_builder::Buffer::default()
+    ///     .node("section")
+    ///     .content(|buf| {
+    ///         let tag = format!("h{level}");
+    ///         buf.node(&tag).content(|buf| buf.text("Title"))
+    ///     })
+    ///     .into_inner();
+    /// assert_eq!(html, "<section><h2>Title</h2></section>");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the tag name is not valid, see [name::is_valid_tag_name]. Use
+    /// [Buffer::try_node] to get an error instead.
+    ///
+    /// ```rust,should_panic
+    /// another_html_builder::Buffer::default()
+    ///     .node("div onclick=alert(1)")
+    ///     .close();
+    /// ```
+    pub fn node<'t>(self, tag: &'t str) -> Buffer<W, Element<'a, 't>> {
+        self.try_node(tag).unwrap()
     }
 
-    pub fn try_node(mut self, tag: &'a str) -> Result<Buffer<W, Element<'a>>, W::Error> {
-        self.inner.write_char('<')?;
-        self.inner.write_str(tag)?;
-        Ok(Buffer {
-            inner: self.inner,
-            current: Element {
-                name: tag,
-                parent: self.current,
-            },
-        })
+    pub fn try_node<'t>(self, tag: &'t str) -> Result<Buffer<W, Element<'a, 't>>, Error<W::Error>> {
+        let mut buffer = self.map_current(|parent| Element { name: tag, parent });
+        if !name::is_valid_tag_name(tag) {
+            let cause = Cause::InvalidName(tag.to_string());
+            buffer.fail(Operation::TagName, cause)?;
+            return Ok(buffer);
+        }
+        buffer.attempt(Operation::OpenTag, |buf| {
+            if let Some(ref pretty) = buf.pretty {
+                if buf.current.parent.is_formatted() && !pretty::is_inline_element(tag) {
+                    if buf.layout.started {
+                        buf.inner
+                            .write(pretty.line_break(buf.current.parent.depth()))?;
+                    }
+                    buf.layout.nested_block = true;
+                }
+            }
+            buf.layout.started = true;
+            buf.inner.write_char('<')?;
+            buf.inner.write_str(tag)
+        })?;
+        Ok(buffer)
     }
 
     /// Appends some raw content implementing [Display](std::fmt::Display)
     ///
     /// This will not escape the provided value.
-    pub fn raw<V: std::fmt::Display>(mut self, value: V) -> Self {
-        self.inner.write(value).unwrap();
-        self
+    pub fn raw<V: std::fmt::Display>(self, value: V) -> Self {
+        self.try_raw(value).unwrap()
+    }
+
+    pub fn try_raw<V: std::fmt::Display>(mut self, value: V) -> Result<Self, Error<W::Error>> {
+        self.attempt(Operation::Raw, |buf| buf.inner.write(value))?;
+        Ok(self)
+    }
+
+    /// Appends some text and escape it.
+    ///
+    /// With the [Dialect::Xml] dialect, only the characters required by xml are escaped,
+    /// see [EscapedXmlContent](content::EscapedXmlContent). This can be changed with
+    /// [Buffer::with_escaping].
+    ///
+    /// ```rust
+    /// let html = another_html_builder::Buffer::default()
+    ///     .node("p")
+    ///     .content(|b| b.text("asd\"weiofew!/<>"))
+    ///     .into_inner();
+    /// assert_eq!(html, "<p>asd&quot;weiofew!&#x2F;&lt;&gt;</p>");
+    /// ```
+    pub fn text(self, input: &str) -> Self {
+        self.try_text(input).unwrap()
+    }
+
+    pub fn try_text(mut self, input: &str) -> Result<Self, Error<W::Error>> {
+        self.attempt(Operation::Text, |buf| {
+            buf.inner
+                .write(escape::EscapedStr(input, buf.text_escaper()))
+        })?;
+        Ok(self)
+    }
+
+    /// Appends any value implementing [Display](std::fmt::Display) and escape it like
+    /// [Buffer::text].
+    ///
+    /// The value is escaped while being formatted, without intermediate [String].
+    ///
+    /// ```rust
+    /// let html = another_html_builder::Buffer::default()
+    ///     .node("p")
+    ///     .content(|b| b.text_display(format_args!("{} < {}", 1.5, 2)))
+    ///     .into_inner();
+    /// assert_eq!(html, "<p>1.5 &lt; 2</p>");
+    /// ```
+    pub fn text_display<V: std::fmt::Display>(self, value: V) -> Self {
+        self.try_text_display(value).unwrap()
+    }
+
+    pub fn try_text_display<V: std::fmt::Display>(
+        mut self,
+        value: V,
+    ) -> Result<Self, Error<W::Error>> {
+        self.attempt(Operation::Text, |buf| {
+            buf.inner.write(escape::Escaped(value, buf.text_escaper()))
+        })?;
+        Ok(self)
+    }
+
+    /// Appends the content of a `script` element.
+    ///
+    /// The content is written without character references, but the sequences
+    /// that could close the element early are neutralized,
+    /// see [EscapedScript](content::EscapedScript). With the [Dialect::Xhtml] and
+    /// [Dialect::Xml] dialects, the content is also wrapped in a `CDATA` section,
+    /// see [EscapedXmlScript](content::EscapedXmlScript).
+    ///
+    /// # Panics
+    ///
+    /// Panics when the content contains a `NUL` character, unless the buffer is
+    /// [latched](Buffer::latched). Use [Buffer::try_script] to get an error instead, without writing anything.
+    ///
+    /// ```rust
+    /// let html = another_html_builder::Buffer::default()
+    ///     .node("script")
+    ///     .content(|buf| buf.script("console.log('</script>' && 1 < 2);"))
+    ///     .into_inner();
+    /// assert_eq!(
+    ///     html,
+    ///     "<script>console.log('\\x3C/script>' && 1 < 2);</script>"
+    /// );
+    /// ```
+    pub fn script(self, input: &str) -> Self {
+        self.try_script(input).unwrap()
+    }
+
+    pub fn try_script(mut self, input: &str) -> Result<Self, Error<W::Error>> {
+        if input.contains('\0') {
+            self.fail(Operation::Text, Cause::NulCharacter)?;
+            return Ok(self);
+        }
+        self.attempt(Operation::Text, |buf| match buf.dialect {
+            Dialect::Html5 => buf.inner.write(content::EscapedScript(input)),
+            Dialect::Xhtml | Dialect::Xml => buf.inner.write(content::EscapedXmlScript(input)),
+        })?;
+        Ok(self)
+    }
+
+    /// Appends the content of a `style` element.
+    ///
+    /// The content is written without character references, but the sequences
+    /// that could close the element early are neutralized,
+    /// see [EscapedStyle](content::EscapedStyle). With the [Dialect::Xhtml] and
+    /// [Dialect::Xml] dialects, the content is also wrapped in a `CDATA` section,
+    /// see [EscapedXmlStyle](content::EscapedXmlStyle).
+    ///
+    /// # Panics
+    ///
+    /// Panics when the content contains a `NUL` character, unless the buffer is
+    /// [latched](Buffer::latched). Use [Buffer::try_style] to get an error instead, without writing anything.
+    ///
+    /// ```rust
+    /// let html = another_html_builder::Buffer::default()
+    ///     .node("style")
+    ///     .content(|buf| buf.style("a > b::after { content: '</style>'; }"))
+    ///     .into_inner();
+    /// assert_eq!(
+    ///     html,
+    ///     "<style>a > b::after { content: '\\3C/style>'; }</style>"
+    /// );
+    /// ```
+    pub fn style(self, input: &str) -> Self {
+        self.try_style(input).unwrap()
     }
 
-    pub fn try_raw<V: std::fmt::Display>(mut self, value: V) -> Result<Self, W::Error> {
-        self.inner.write(value)?;
+    pub fn try_style(mut self, input: &str) -> Result<Self, Error<W::Error>> {
+        if input.contains('\0') {
+            self.fail(Operation::Text, Cause::NulCharacter)?;
+            return Ok(self);
+        }
+        self.attempt(Operation::Text, |buf| match buf.dialect {
+            Dialect::Html5 => buf.inner.write(content::EscapedStyle(input)),
+            Dialect::Xhtml | Dialect::Xml => buf.inner.write(content::EscapedXmlStyle(input)),
+        })?;
         Ok(self)
     }
 
-    /// Appends some text and escape it.
+    /// Appends a value serialized as json, that can be embedded in a `script` element.
+    ///
+    /// This is only available with the `serde` feature, see [EscapedJson](json::EscapedJson).
+    ///
+    /// # Panics
+    ///
+    /// Panics when the value cannot be serialized, unless the buffer is
+    /// [latched](Buffer::latched). Use [Buffer::try_json] to get the [serde_json::Error] instead.
     ///
     /// ```rust
+    /// #[derive(serde::Serialize)]
+    /// struct State {
+    ///     name: &'static str,
+    /// }
+    ///
     /// let html = another_html_builder::Buffer::default()
-    ///     .node("p")
-    ///     .content(|b| b.text("asd\"weiofew!/<>"))
+    ///     .node("script")
+    ///     .attr(("type", "application/json"))
+    ///     .content(|buf| buf.json(&State { name: "</script>" }))
     ///     .into_inner();
-    /// assert_eq!(html, "<p>asd&quot;weiofew!&#x2F;&lt;&gt;</p>");
+    /// assert_eq!(
+    ///     html,
+    ///     "<script type=\"application/json\">{\"name\":\"\\u003c/script\\u003e\"}</script>"
+    /// );
     /// ```
-    pub fn text(mut self, input: &str) -> Self {
-        self.inner.write(content::EscapedContent(input)).unwrap();
-        self
+    #[cfg(feature = "serde")]
+    pub fn json<V: serde::Serialize + ?Sized>(self, value: &V) -> Self {
+        self.try_json(value).unwrap()
     }
 
-    pub fn try_text(mut self, input: &str) -> Result<Self, W::Error> {
-        self.inner.write(content::EscapedContent(input))?;
+    #[cfg(feature = "serde")]
+    pub fn try_json<V: serde::Serialize + ?Sized>(
+        mut self,
+        value: &V,
+    ) -> Result<Self, Error<W::Error>> {
+        if let Err(cause) = json::write(&mut self.inner, value) {
+            self.fail(Operation::Text, cause)?;
+        }
         Ok(self)
     }
 }
 
-impl<'a, W: WriterExt> Buffer<W, Element<'a>> {
+impl<'a, W: WriterExt> Buffer<W, Element<'a, '_>> {
     /// Appends an attribute to the current node.
     ///
     /// For more information about how to extend attributes, take a look at the [crate::attribute::Attribute] trait.
@@ -333,23 +1260,38 @@ impl<'a, W: WriterExt> Buffer<W, Element<'a>> {
     ///     .into_inner();
     /// assert_eq!(
     ///     html,
-    ///     "<p single hello=\"world\" number=\"42\" foo=\"bar\" here />"
+    ///     "<p single hello=\"world\" number=\"42\" foo=\"bar\" here></p>"
     /// );
     /// ```
-    pub fn attr<T>(mut self, attr: T) -> Self
+    ///
+    /// # Panics
+    ///
+    /// Panics if the attribute name is not valid, see [name::is_valid_attribute_name].
+    /// Use [Buffer::try_attr] to get an error instead.
+    pub fn attr<T>(self, attr: T) -> Self
     where
-        attribute::Attribute<T>: std::fmt::Display,
+        attribute::Attribute<T>: attribute::RenderAttribute,
     {
-        self.inner.write(attribute::Attribute(attr)).unwrap();
-        self
+        self.try_attr(attr).unwrap()
     }
 
     #[inline]
-    pub fn try_attr<T>(mut self, attr: T) -> Result<Self, W::Error>
+    pub fn try_attr<T>(mut self, attr: T) -> Result<Self, Error<W::Error>>
     where
-        attribute::Attribute<T>: std::fmt::Display,
+        attribute::Attribute<T>: attribute::RenderAttribute,
     {
-        self.inner.write(attribute::Attribute(attr))?;
+        let attr = attribute::Attribute(attr);
+        if let Some(name) = attribute::RenderAttribute::invalid_name(&attr) {
+            self.fail(Operation::AttributeName, Cause::InvalidName(name))?;
+            return Ok(self);
+        }
+        self.attempt(Operation::Attribute, |buf| {
+            let mut attr = attr.with_dialect(buf.dialect);
+            if let Some(escaping) = buf.escaping {
+                attr = attr.with_escaping(escaping);
+            }
+            buf.inner.write(attr)
+        })?;
         Ok(self)
     }
 
@@ -364,12 +1306,12 @@ impl<'a, W: WriterExt> Buffer<W, Element<'a>> {
     ///     .cond_attr(false, "not-here")
     ///     .close()
     ///     .into_inner();
-    /// assert_eq!(html, "<p foo=\"bar\" here />");
+    /// assert_eq!(html, "<p foo=\"bar\" here></p>");
     /// ```
     #[inline]
     pub fn cond_attr<T>(self, condition: bool, attr: T) -> Self
     where
-        attribute::Attribute<T>: std::fmt::Display,
+        attribute::Attribute<T>: attribute::RenderAttribute,
     {
         if condition {
             self.attr(attr)
@@ -379,9 +1321,9 @@ impl<'a, W: WriterExt> Buffer<W, Element<'a>> {
     }
 
     #[inline]
-    pub fn try_cond_attr<T>(self, condition: bool, attr: T) -> Result<Self, W::Error>
+    pub fn try_cond_attr<T>(self, condition: bool, attr: T) -> Result<Self, Error<W::Error>>
     where
-        attribute::Attribute<T>: std::fmt::Display,
+        attribute::Attribute<T>: attribute::RenderAttribute,
     {
         if condition {
             self.try_attr(attr)
@@ -392,27 +1334,35 @@ impl<'a, W: WriterExt> Buffer<W, Element<'a>> {
 
     /// Closes the current node without providing any content
     ///
+    /// Depending on the [Dialect] of the buffer, this will write a void element,
+    /// a closing tag or a self closing element.
+    ///
     /// ```rust
     /// let html = another_html_builder::Buffer::default()
     ///     .node("p")
     ///     .close()
+    ///     .node("br")
+    ///     .close()
     ///     .into_inner();
-    /// assert_eq!(html, "<p />");
+    /// assert_eq!(html, "<p></p><br>");
     /// ```
-    pub fn close(mut self) -> Buffer<W, Body<'a>> {
-        self.inner.write_str(" />").unwrap();
-        Buffer {
-            inner: self.inner,
-            current: self.current.parent,
-        }
+    pub fn close(self) -> Buffer<W, Body<'a>> {
+        self.try_close().unwrap()
     }
 
-    pub fn try_close(mut self) -> Result<Buffer<W, Body<'a>>, W::Error> {
-        self.inner.write_str(" />")?;
-        Ok(Buffer {
-            inner: self.inner,
-            current: self.current.parent,
-        })
+    pub fn try_close(mut self) -> Result<Buffer<W, Body<'a>>, Error<W::Error>> {
+        self.attempt(Operation::CloseTag, |buf| {
+            match buf.dialect.empty_element_end(buf.current.name) {
+                EmptyElementEnd::Void => buf.inner.write_char('>'),
+                EmptyElementEnd::ClosingTag => {
+                    buf.inner.write_str("></")?;
+                    buf.inner.write_str(buf.current.name)?;
+                    buf.inner.write_char('>')
+                }
+                EmptyElementEnd::SelfClosing => buf.inner.write_str(" />"),
+            }
+        })?;
+        Ok(self.map_current(|current| current.parent))
     }
 
     /// Closes the current node and start writing it's content
@@ -424,68 +1374,39 @@ impl<'a, W: WriterExt> Buffer<W, Element<'a>> {
     ///     .node("div")
     ///     .content(|buf| buf.node("p").close())
     ///     .into_inner();
-    /// assert_eq!(html, "<div><p /></div>");
+    /// assert_eq!(html, "<div><p></p></div>");
     /// ```
-    pub fn content<F>(mut self, children: F) -> Buffer<W, Body<'a>>
+    pub fn content<F>(self, children: F) -> Buffer<W, Body<'a>>
     where
         F: FnOnce(Buffer<W, Body>) -> Buffer<W, Body>,
     {
-        self.inner.write_char('>').unwrap();
-        let child_buffer = Buffer {
-            inner: self.inner,
-            current: Body::Element {
-                name: self.current.name,
-                parent: Box::new(self.current.parent),
-            },
-        };
-        let Buffer { mut inner, current } = children(child_buffer);
-        match current {
-            Body::Element { name, parent } => {
-                inner.write_str("</").unwrap();
-                inner.write_str(name).unwrap();
-                inner.write_char('>').unwrap();
-                Buffer {
-                    inner,
-                    current: *parent,
-                }
-            }
-            // This should never happen
-            Body::Root => Buffer {
-                inner,
-                current: Body::Root,
-            },
-        }
+        self.try_content(|buf| Ok(children(buf))).unwrap()
     }
 
-    pub fn try_content<F>(mut self, children: F) -> Result<Buffer<W, Body<'a>>, W::Error>
+    pub fn try_content<F>(mut self, children: F) -> Result<Buffer<W, Body<'a>>, Error<W::Error>>
     where
-        F: FnOnce(Buffer<W, Body>) -> Result<Buffer<W, Body>, W::Error>,
+        F: FnOnce(Buffer<W, Body>) -> Result<Buffer<W, Body>, Error<W::Error>>,
     {
-        self.inner.write_char('>')?;
-        let child_buffer = Buffer {
-            inner: self.inner,
-            current: Body::Element {
-                name: self.current.name,
-                parent: Box::new(self.current.parent),
-            },
-        };
-        let Buffer { mut inner, current } = children(child_buffer)?;
-        match current {
-            Body::Element { name, parent } => {
-                inner.write_str("</")?;
-                inner.write_str(name)?;
-                inner.write_char('>')?;
-                Ok(Buffer {
-                    inner,
-                    current: *parent,
-                })
+        self.attempt(Operation::OpenTag, |buf| buf.inner.write_char('>'))?;
+        let Element { parent, name } = self.current;
+        let layout = self.layout;
+        let mut child_buffer = self.map_current(|_| Body::element(name, &parent));
+        child_buffer.layout.nested_block = false;
+        let mut child_buffer = children(child_buffer)?;
+        child_buffer.attempt(Operation::CloseTag, |buf| {
+            if let Some(ref pretty) = buf.pretty {
+                if buf.layout.nested_block {
+                    buf.inner
+                        .write(pretty.line_break(buf.current.depth() - 1))?;
+                }
             }
-            // This should never happen
-            Body::Root => Ok(Buffer {
-                inner,
-                current: Body::Root,
-            }),
-        }
+            buf.inner.write_str("</")?;
+            buf.inner.write_str(name)?;
+            buf.inner.write_char('>')
+        })?;
+        let mut buffer = child_buffer.map_current(|_| parent);
+        buffer.layout = layout;
+        Ok(buffer)
     }
 }
 
@@ -542,7 +1463,7 @@ mod tests {
             .into_inner();
         assert_eq!(
             html,
-            "<!DOCTYPE html><html lang=\"en\"><head><meta charset=\"utf-8\" /><meta name=\"viewport\" content=\"width=device-width, initial-scale=1\" /></head><body /></html>"
+            "<!DOCTYPE html><html lang=\"en\"><head><meta charset=\"utf-8\"><meta name=\"viewport\" content=\"width=device-width, initial-scale=1\"></head><body></body></html>"
         );
     }
 
@@ -556,7 +1477,7 @@ mod tests {
             .into_inner();
         assert_eq!(
             html,
-            "<a title=\"Let's add a quote \\\" like this\" href=\"http://example.com?whatever=here\">Click me!</a>"
+            "<a title=\"Let's add a quote &quot; like this\" href=\"http://example.com?whatever=here\">Click me!</a>"
         );
     }
 
@@ -579,7 +1500,7 @@ mod tests {
             .attr(None::<&str>)
             .close()
             .into_inner();
-        assert_eq!(html, "<p foo=\"bar\" here />");
+        assert_eq!(html, "<p foo=\"bar\" here></p>");
     }
 
     #[test]
@@ -588,11 +1509,16 @@ mod tests {
             .node("p")
             .attr(("foo", "bar"))
             .attr(("bool", true))
+            .attr(("unset", false))
+            .attr(("aria-hidden", attribute::TrueFalse(true)))
             .attr(("u8", 42u8))
             .attr(("i8", -1i8))
             .close()
             .into_inner();
-        assert_eq!(html, "<p foo=\"bar\" bool=\"true\" u8=\"42\" i8=\"-1\" />");
+        assert_eq!(
+            html,
+            "<p foo=\"bar\" bool aria-hidden=\"true\" u8=\"42\" i8=\"-1\"></p>"
+        );
     }
 
     #[test]
@@ -605,7 +1531,210 @@ mod tests {
             .cond_attr(false, "not-here")
             .close()
             .into_inner();
-        assert_eq!(html, "<p foo=\"bar\" here />");
+        assert_eq!(html, "<p foo=\"bar\" here></p>");
+    }
+
+    #[test_case::test_case(Dialect::Html5, "<div><br><img src=\"a.png\"><p></p></div>"; "html5")]
+    #[test_case::test_case(Dialect::Xhtml, "<div><br /><img src=\"a.png\" /><p /></div>"; "xhtml")]
+    fn with_empty_elements(dialect: Dialect, expected: &str) {
+        let html = Buffer::default()
+            .with_dialect(dialect)
+            .node("div")
+            .content(|buf| {
+                buf.node("br")
+                    .close()
+                    .node("img")
+                    .attr(("src", "a.png"))
+                    .close()
+                    .node("p")
+                    .close()
+            })
+            .into_inner();
+        assert_eq!(html, expected);
+    }
+
+    #[test_case::test_case(Dialect::Html5, None, "<p title=\"l'été\">l&#x27;été &gt; &#x2F;</p>"; "html5 default")]
+    #[test_case::test_case(Dialect::Xml, None, "<p title=\"l'été\">l'été &gt; /</p>"; "xml default")]
+    #[test_case::test_case(Dialect::Html5, Some(Escaping::Minimal), "<p title=\"l'été\">l'été > /</p>"; "minimal")]
+    #[test_case::test_case(Dialect::Xml, Some(Escaping::Strict), "<p title=\"l'été\">l&#x27;été &gt; &#x2F;</p>"; "strict")]
+    #[test_case::test_case(Dialect::Html5, Some(Escaping::AsciiOnly), "<p title=\"l'&#233;t&#233;\">l&#x27;&#xE9;t&#xE9; &gt; &#x2F;</p>"; "ascii only")]
+    fn with_escaping(dialect: Dialect, escaping: Option<Escaping>, expected: &str) {
+        let mut buffer = Buffer::default().with_dialect(dialect);
+        if let Some(escaping) = escaping {
+            buffer = buffer.with_escaping(escaping);
+        }
+        let html = buffer
+            .node("p")
+            .attr(("title", "l'été"))
+            .content(|buf| buf.text("l'été > /"))
+            .into_inner();
+        assert_eq!(html, expected);
+    }
+
+    #[test_case::test_case(Dialect::Html5, None, "<p>1 &lt; 2.5 &#x2F; &quot;a&quot;</p>"; "html5 default")]
+    #[test_case::test_case(Dialect::Xml, None, "<p>1 &lt; 2.5 / \"a\"</p>"; "xml default")]
+    #[test_case::test_case(Dialect::Html5, Some(Escaping::Minimal), "<p>1 &lt; 2.5 / \"a\"</p>"; "minimal")]
+    fn with_text_display(dialect: Dialect, escaping: Option<Escaping>, expected: &str) {
+        let mut buffer = Buffer::default().with_dialect(dialect);
+        if let Some(escaping) = escaping {
+            buffer = buffer.with_escaping(escaping);
+        }
+        let html = buffer
+            .node("p")
+            .content(|buf| buf.text_display(format_args!("{} < {} / {:?}", 1, 2.5, "a")))
+            .into_inner();
+        assert_eq!(html, expected);
+    }
+
+    #[test]
+    fn xhtml_document() {
+        let html = Buffer::default()
+            .with_dialect(Dialect::Xhtml)
+            .doctype()
+            .node("html")
+            .attr((attribute::Xmlns(None), "http://www.w3.org/1999/xhtml"))
+            .content(|buf| {
+                buf.node("select").content(|buf| {
+                    buf.node("option")
+                        .attr("selected")
+                        .content(|buf| buf.text("'a' & b"))
+                })
+            })
+            .into_inner();
+        assert_eq!(
+            html,
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?><!DOCTYPE html><html xmlns=\"http://www.w3.org/1999/xhtml\"><select><option selected=\"selected\">&#x27;a&#x27; &amp; b</option></select></html>"
+        );
+    }
+
+    #[test]
+    fn xml_document() {
+        let xml = Buffer::default()
+            .with_dialect(Dialect::Xml)
+            .doctype()
+            .node("feed")
+            .attr((attribute::Xmlns(None), "http://www.w3.org/2005/Atom"))
+            .content(|buf| {
+                buf.node("title")
+                    .content(|buf| buf.text("'a' & \"b\" / <c>"))
+                    .node("link")
+                    .attr(("href", "http://example.com/"))
+                    .close()
+            })
+            .into_inner();
+        assert_eq!(
+            xml,
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?><feed xmlns=\"http://www.w3.org/2005/Atom\"><title>'a' &amp; \"b\" / &lt;c&gt;</title><link href=\"http://example.com/\" /></feed>"
+        );
+    }
+
+    #[test]
+    fn pretty_html() {
+        let html = Buffer::default()
+            .pretty(Pretty::default())
+            .node("div")
+            .content(|buf| {
+                buf.text("Hello ")
+                    .node("span")
+                    .content(|buf| buf.node("div").content(|buf| buf.text("inline")))
+                    .node("ul")
+                    .content(|buf| {
+                        buf.node("li")
+                            .content(|buf| buf.text("first"))
+                            .node("li")
+                            .close()
+                    })
+                    .node("textarea")
+                    .content(|buf| buf.text("  as is\n"))
+            })
+            .node("p")
+            .close()
+            .node("br")
+            .close()
+            .into_inner();
+        assert_eq!(
+            html,
+            "<div>Hello <span><div>inline</div></span>\n  <ul>\n    <li>first</li>\n    <li></li>\n  </ul><textarea>  as is\n</textarea>\n</div>\n<p></p><br>"
+        );
+    }
+
+    #[test_case::test_case(Dialect::Html5, "<head><style>p > a { content: \"]]>\" }</style><script>a < b && c</script></head>"; "html")]
+    #[test_case::test_case(Dialect::Xhtml, "<head><style>/*<![CDATA[*/p > a { content: \"]]]]><![CDATA[>\" }/*]]>*/</style><script>/*<![CDATA[*/a < b && c/*]]>*/</script></head>"; "xhtml")]
+    #[test_case::test_case(Dialect::Xml, "<head><style>/*<![CDATA[*/p > a { content: \"]]]]><![CDATA[>\" }/*]]>*/</style><script>/*<![CDATA[*/a < b && c/*]]>*/</script></head>"; "xml")]
+    fn with_script_and_style_in_dialect(dialect: Dialect, expected: &str) {
+        let html = Buffer::default()
+            .with_dialect(dialect)
+            .node("head")
+            .content(|buf| {
+                buf.node("style")
+                    .content(|buf| buf.style("p > a { content: \"]]>\" }"))
+                    .node("script")
+                    .content(|buf| buf.script("a < b && c"))
+            })
+            .into_inner();
+        assert_eq!(html, expected);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn should_fail_with_invalid_json() {
+        let value = std::collections::BTreeMap::from([(vec![1], 2)]);
+        let error = Buffer::default()
+            .node("script")
+            .try_content(|buf| buf.try_json(&value))
+            .err()
+            .unwrap();
+        assert!(matches!(error.cause(), Cause::Json(_)));
+        assert_eq!(error.operation(), Operation::Text);
+
+        let (sender, receiver) = std::sync::mpsc::channel();
+        drop(receiver);
+        let error = Buffer::from(chunk::ChunkedWriter::new(sender, 4))
+            .node("p")
+            .try_content(|buf| buf.try_json(&["abcd"]))
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.inner().map(std::io::Error::kind),
+            Some(std::io::ErrorKind::BrokenPipe)
+        );
+    }
+
+    #[test]
+    fn with_script_and_style() {
+        let html = Buffer::default()
+            .node("head")
+            .content(|buf| {
+                buf.node("style")
+                    .content(|buf| buf.style("p::before { content: \"</style>\" }"))
+                    .node("script")
+                    .content(|buf| buf.script("const a = \"<!-- </script>\";"))
+            })
+            .into_inner();
+        assert_eq!(
+            html,
+            "<head><style>p::before { content: \"\\3C/style>\" }</style><script>const a = \"\\x3C!-- \\x3C/script>\";</script></head>"
+        );
+    }
+
+    #[test]
+    fn should_fail_with_unsafe_script() {
+        let mut output = String::new();
+        let error = Buffer::from(&mut output)
+            .node("script")
+            .try_content(|buf| buf.try_script("a\0b"))
+            .err()
+            .unwrap();
+        assert!(matches!(error.cause(), Cause::NulCharacter));
+        assert_eq!(error.operation(), Operation::Text);
+        assert_eq!(output, "<script>");
+
+        let error = Buffer::default()
+            .node("style")
+            .try_content(|buf| buf.try_style("\0"))
+            .err()
+            .unwrap();
+        assert!(matches!(error.cause(), Cause::NulCharacter));
     }
 
     #[test]
@@ -635,6 +1764,386 @@ mod tests {
         assert_eq!(html, "<div>This is an error</div>");
     }
 
+    #[test]
+    fn with_iterations() {
+        let html = Buffer::default()
+            .node("ul")
+            .content(|buf| {
+                buf.iter(["a", "b"], |buf, index, item| {
+                    buf.node("li").content(|buf| buf.raw(index).text(item))
+                })
+            })
+            .node("p")
+            .content(|buf| {
+                buf.join(
+                    Vec::<&str>::new(),
+                    |buf| buf.text(","),
+                    |buf, item| buf.text(item),
+                )
+            })
+            .node("p")
+            .content(|buf| {
+                buf.join(
+                    ["a", "b"],
+                    |buf| buf.node("br").close(),
+                    |buf, item| buf.text(item),
+                )
+            })
+            .into_inner();
+        assert_eq!(html, "<ul><li>0a</li><li>1b</li></ul><p></p><p>a<br>b</p>");
+    }
+
+    #[test]
+    fn with_fallible_branches() {
+        let html = Buffer::default()
+            .try_cond_else(false, |buf| buf.try_text("a"), |buf| buf.try_text("b"))
+            .and_then(|buf| {
+                buf.try_optional_else(Some("c"), |buf, item| buf.try_text(item), |buf| Ok(buf))
+            })
+            .and_then(|buf| {
+                buf.try_either(
+                    Either::<&str, u8>::Right(4),
+                    |buf, item| buf.try_text(item),
+                    |buf, item| buf.try_raw(item),
+                )
+            })
+            .and_then(|buf| buf.try_iter(["e"], |buf, _, item| buf.try_text(item)))
+            .and_then(|buf| {
+                buf.try_join(
+                    ["f", "g"],
+                    |buf| buf.try_text("-"),
+                    |buf, item| buf.try_text(item),
+                )
+            })
+            .unwrap()
+            .into_inner();
+        assert_eq!(html, "bc4ef-g");
+    }
+
+    #[cfg(feature = "macros")]
+    #[test]
+    fn with_html_macro() {
+        enum Kind {
+            Link(&'static str),
+            Text,
+        }
+
+        let kinds = [Kind::Link("/a?b&c"), Kind::Text];
+        let checked = true;
+        let html = Buffer::default()
+            .node("div")
+            .content(html! {
+                <section id="main" {("hidden", checked)}>
+                    for (index, kind) in kinds.iter().enumerate() {
+                        match kind {
+                            Kind::Link(href) if index == 0 => { <a href={*href}>"first"</a> }
+                            Kind::Link(href) => { <a href={*href} /> }
+                            Kind::Text => { {"<text>"} }
+                        }
+                    }
+                    if checked { <input type="checkbox" checked /> } else if index() { "unreachable" }
+                    @{Some(render::from_fn(|buf| buf.text("a")))}
+                    <p>{kinds.len()} " items, " {1.5} " < " {'2'}</p>
+                </section>
+            })
+            .into_inner();
+        assert_eq!(
+            html,
+            "<div><section id=\"main\" hidden><a href=\"/a?b&amp;c\">first</a>&lt;text&gt;<input type=\"checkbox\" checked>a<p>2 items, 1.5 &lt; 2</p></section></div>"
+        );
+
+        fn index() -> bool {
+            false
+        }
+    }
+
+    #[test]
+    fn with_invalid_names() {
+        assert!(Buffer::default().try_node("div onclick=alert(1)").is_err());
+        assert!(Buffer::default()
+            .node("div")
+            .try_attr(("a\" onclick=\"alert(1)", "b"))
+            .is_err());
+        let buf = Buffer::from(Cursor::new(Vec::new()));
+        assert!(buf.try_node("<script>").is_err());
+    }
+
+    #[test]
+    fn should_not_write_invalid_names() {
+        let mut output = String::new();
+        let result = Buffer::from(&mut output)
+            .pretty(Pretty::default())
+            .node("div")
+            .try_content(|buf| buf.node("p").close().try_node("a b")?.try_close());
+        assert!(result.is_err());
+        assert_eq!(output, "<div>\n  <p></p>");
+
+        let mut output = String::new();
+        let result = Buffer::from(&mut output)
+            .node("div")
+            .attr(("id", "a"))
+            .try_attr(("b c", "d"));
+        assert!(result.is_err());
+        assert_eq!(output, "<div id=\"a\"");
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn should_write_to_tokio_writer() {
+        fn assert_send<T: Send>(value: T) -> T {
+            value
+        }
+
+        let buf = Buffer::from(prelude::TokioWriter::new(Vec::new()))
+            .node("div")
+            .content(|buf| buf.text("Hello"));
+        let buf = assert_send(buf.flush_async()).await.unwrap();
+        let buf = buf.node("p").close().flush_async().await.unwrap();
+        assert!(buf.inner.pending().is_empty());
+        assert_eq!(buf.into_inner(), b"<div>Hello</div><p></p>");
+    }
+
+    #[cfg(feature = "futures")]
+    #[tokio::test]
+    async fn should_write_to_futures_writer() {
+        let writer = futures_util::io::Cursor::new(Vec::new());
+        let buf = Buffer::from(prelude::FuturesWriter::new(writer))
+            .node("div")
+            .content(|buf| buf.text("Hello"));
+        assert_eq!(buf.inner.pending(), b"<div>Hello</div>");
+        let buf = buf.flush_async().await.unwrap();
+        let buf = buf.node("p").close().flush_async().await.unwrap();
+        assert!(buf.inner.pending().is_empty());
+        assert_eq!(buf.into_inner().into_inner(), b"<div>Hello</div><p></p>");
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn should_resume_flush_after_error() {
+        use std::pin::Pin;
+        use std::task::{Context, Poll};
+
+        /// Accepts 3 bytes at a time and fails once after the first write.
+        struct Flaky {
+            output: Vec<u8>,
+            failed: bool,
+        }
+
+        impl tokio::io::AsyncWrite for Flaky {
+            fn poll_write(
+                mut self: Pin<&mut Self>,
+                _: &mut Context<'_>,
+                buf: &[u8],
+            ) -> Poll<std::io::Result<usize>> {
+                if !self.failed && !self.output.is_empty() {
+                    self.failed = true;
+                    return Poll::Ready(Err(std::io::ErrorKind::Interrupted.into()));
+                }
+                let size = buf.len().min(3);
+                self.output.extend_from_slice(&buf[..size]);
+                Poll::Ready(Ok(size))
+            }
+
+            fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+                Poll::Ready(Ok(()))
+            }
+
+            fn poll_shutdown(
+                self: Pin<&mut Self>,
+                _: &mut Context<'_>,
+            ) -> Poll<std::io::Result<()>> {
+                Poll::Ready(Ok(()))
+            }
+        }
+
+        let mut writer = prelude::TokioWriter::new(Flaky {
+            output: Vec::new(),
+            failed: false,
+        });
+        writer.write_str("<p>Hello</p>").unwrap();
+        assert!(writer.flush_async().await.is_err());
+        assert_eq!(writer.pending(), b"Hello</p>");
+        writer.flush_async().await.unwrap();
+        assert!(writer.pending().is_empty());
+        assert_eq!(writer.into_inner().output, b"<p>Hello</p>");
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn should_flush_in_spawned_task() {
+        async fn render<W>(buf: Buffer<W, Body<'static>>) -> Result<(), Error<W::Error>>
+        where
+            W: AsyncWriterExt + Send + 'static,
+            W::Error: Send,
+        {
+            let handle = tokio::spawn(buf.node("p").close().flush_async());
+            handle.await.unwrap().map(|_| ())
+        }
+
+        let (writer, mut reader) = tokio::io::duplex(64);
+        render(Buffer::from(prelude::TokioWriter::new(writer)))
+            .await
+            .unwrap();
+        let mut output = Vec::new();
+        tokio::io::AsyncReadExt::read_to_end(&mut reader, &mut output)
+            .await
+            .unwrap();
+        assert_eq!(output, b"<p></p>");
+    }
+
+    #[cfg(feature = "futures")]
+    #[tokio::test]
+    async fn should_stream_chunks() {
+        use futures_util::StreamExt;
+
+        let (sender, receiver) = futures_channel::mpsc::unbounded();
+        let writer = chunk::ChunkedWriter::new(sender, 4);
+        let buf = Buffer::from(writer)
+            .node("p")
+            .content(|buf| buf.text("Hello"));
+        drop(buf.into_inner().finish().unwrap());
+        let chunks: Vec<Vec<u8>> = receiver.collect().await;
+        assert_eq!(
+            chunks,
+            [b"<p>H".to_vec(), b"ello".to_vec(), b"</p>".to_vec()]
+        );
+    }
+
+    #[test]
+    fn should_flush_chunks() {
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let buf = Buffer::from(chunk::ChunkedWriter::new(sender, 64))
+            .node("head")
+            .close()
+            .try_flush()
+            .unwrap();
+        assert_eq!(receiver.try_recv().unwrap(), b"<head></head>");
+        let buf = buf.node("body").close();
+        assert!(receiver.try_recv().is_err());
+        let _ = buf.flush();
+        assert_eq!(receiver.try_recv().unwrap(), b"<body></body>");
+    }
+
+    #[test]
+    fn with_error_location() {
+        let error = Buffer::default()
+            .node("html")
+            .try_content(|buf| buf.node("body").try_attr(("a b", "c"))?.try_close())
+            .err()
+            .unwrap();
+        assert_eq!(error.path(), "$ > html > body");
+        assert_eq!(error.operation(), Operation::AttributeName);
+        assert!(matches!(error.cause(), Cause::InvalidName(name) if name == "a b"));
+
+        let error = Buffer::default()
+            .node("ul")
+            .try_content(|buf| buf.try_node("li>")?.try_close())
+            .err()
+            .unwrap();
+        assert_eq!(error.path(), "$ > ul > li>");
+        assert_eq!(error.operation(), Operation::TagName);
+        assert_eq!(
+            error.to_string(),
+            "unable to write the tag name at `$ > ul > li>`"
+        );
+
+        let (sender, receiver) = std::sync::mpsc::channel();
+        drop(receiver);
+        let error = Buffer::from(chunk::ChunkedWriter::new(sender, 4))
+            .node("p")
+            .try_content(|buf| buf.try_text("abcd"))
+            .err()
+            .unwrap();
+        assert_eq!(error.path(), "$ > p");
+        assert_eq!(error.operation(), Operation::Text);
+        assert_eq!(
+            error.inner().map(std::io::Error::kind),
+            Some(std::io::ErrorKind::BrokenPipe)
+        );
+    }
+
+    #[test]
+    fn should_latch_rejected_content() {
+        let error = Buffer::default()
+            .latched()
+            .node("p")
+            .content(|buf| {
+                buf.node("a b")
+                    .attr(("c d", "e"))
+                    .content(|buf| buf.script("a\0b"))
+            })
+            .finish()
+            .err()
+            .unwrap();
+        assert_eq!(error.path(), "$ > p > a b");
+        assert_eq!(error.operation(), Operation::TagName);
+        assert!(matches!(error.cause(), Cause::InvalidName(name) if name == "a b"));
+
+        let mut output = String::new();
+        let buf = Buffer::from(&mut output)
+            .latched()
+            .node("script")
+            .content(|buf| buf.script("a\0b"))
+            .node("p")
+            .close();
+        let error = buf.inner.error().unwrap();
+        assert!(matches!(error.cause(), Cause::NulCharacter));
+        assert!(buf.finish().is_err());
+        assert_eq!(output, "<script>");
+    }
+
+    #[test]
+    fn should_latch_first_error() {
+        let mut chunks = Vec::new();
+        let writer = chunk::ChunkedWriter::new(
+            |chunk: Vec<u8>| {
+                if chunks.is_empty() {
+                    chunks.push(chunk);
+                    Ok(())
+                } else {
+                    Err(std::io::Error::from(std::io::ErrorKind::ConnectionReset))
+                }
+            },
+            4,
+        );
+        let error = Buffer::from(writer)
+            .latched()
+            .node("div")
+            .content(|buf| buf.text("Hello World!"))
+            .flush()
+            .finish()
+            .err()
+            .unwrap();
+        assert_eq!(error.path(), "$ > div");
+        assert_eq!(error.operation(), Operation::Text);
+        assert_eq!(
+            error.inner().map(std::io::Error::kind),
+            Some(std::io::ErrorKind::ConnectionReset)
+        );
+        assert_eq!(chunks, [b"<div".to_vec()]);
+    }
+
+    #[test]
+    fn should_return_display_error_from_io_buffer() {
+        struct Failing;
+
+        impl std::fmt::Display for Failing {
+            fn fmt(&self, _: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                Err(std::fmt::Error)
+            }
+        }
+
+        let error = Buffer::from(Cursor::new(Vec::new()))
+            .try_raw(Failing)
+            .err()
+            .unwrap();
+        assert!(matches!(error.cause(), Cause::Writer(_)));
+        assert_eq!(
+            error.inner().map(std::io::Error::kind),
+            Some(std::io::ErrorKind::InvalidData)
+        );
+    }
+
     #[test]
     fn should_write_to_io_buffer() {
         let buf = Buffer::from(Cursor::new(Vec::new()));
@@ -0,0 +1,232 @@
+//! Name related module. This contains the validation of the tag and attribute names.
+//!
+//! Names are written as is in the [Buffer](crate::Buffer), so they are checked to never
+//! contain anything that could end the tag or the attribute early and inject markup.
+//! The [Buffer](crate::Buffer) validates them at runtime, and the [tag_name](crate::tag_name)
+//! and [attribute_name](crate::attribute_name) macros allow to validate literals at compile time.
+//!
+//! See <https://html.spec.whatwg.org/multipage/syntax.html#syntax-tag-name> and
+//! <https://html.spec.whatwg.org/multipage/syntax.html#syntax-attribute-name>.
+
+/// Checks that the name only contains characters allowed in tag and attribute names.
+///
+/// Spaces, control characters, quotes, `/`, `<`, `=` and `>` are forbidden.
+const fn has_valid_characters(name: &[u8]) -> bool {
+    let mut index = 0;
+    while index < name.len() {
+        match name[index] {
+            b'\0'..=b' ' | b'"' | b'\'' | b'/' | b'<' | b'=' | b'>' | 0x7F => return false,
+            // C1 control characters, from U+0080 to U+009F
+            0xC2 if index + 1 < name.len() && matches!(name[index + 1], 0x80..=0x9F) => {
+                return false;
+            }
+            _ => {}
+        }
+        index += 1;
+    }
+    true
+}
+
+/// Checks if the given name can be used as a tag name.
+///
+/// A tag name starts with an ascii letter and doesn't contain any space,
+/// control character, quote, `/`, `<`, `=` or `>`.
+///
+/// ```rust
+/// use another_html_builder::name::is_valid_tag_name;
+///
+/// assert!(is_valid_tag_name("div"));
+/// assert!(is_valid_tag_name("my-element"));
+/// assert!(!is_valid_tag_name("div onclick=alert(1)"));
+/// assert!(!is_valid_tag_name("-div"));
+/// ```
+pub const fn is_valid_tag_name(name: &str) -> bool {
+    let bytes = name.as_bytes();
+    !bytes.is_empty() && bytes[0].is_ascii_alphabetic() && has_valid_characters(bytes)
+}
+
+/// Checks if the given name can be used as an attribute name.
+///
+/// An attribute name is not empty and doesn't contain any space, control character,
+/// quote, `/`, `<`, `=` or `>`.
+///
+/// ```rust
+/// use another_html_builder::name::is_valid_attribute_name;
+///
+/// assert!(is_valid_attribute_name("data-id"));
+/// assert!(is_valid_attribute_name("@click"));
+/// assert!(!is_valid_attribute_name("a=b"));
+/// assert!(!is_valid_attribute_name(""));
+/// ```
+pub const fn is_valid_attribute_name(name: &str) -> bool {
+    !name.is_empty() && has_valid_characters(name.as_bytes())
+}
+
+/// Checks if the given name can be used as a css property, in a
+/// [Style](crate::attribute::Style).
+///
+/// A property is a css identifier: it starts with a letter, `_`, a non ascii character,
+/// or a `-` followed by one of those or by another `-` for custom properties, and then
+/// only contains letters, digits, `-`, `_` or non ascii characters.
+///
+/// See <https://www.w3.org/TR/css-syntax-3/#ident-token-diagram>.
+///
+/// ```rust
+/// use another_html_builder::name::is_valid_css_property;
+///
+/// assert!(is_valid_css_property("margin-top"));
+/// assert!(is_valid_css_property("-webkit-box-shadow"));
+/// assert!(is_valid_css_property("--main-color"));
+/// assert!(!is_valid_css_property("color:red;background"));
+/// assert!(!is_valid_css_property("1px"));
+/// ```
+pub const fn is_valid_css_property(name: &str) -> bool {
+    const fn is_start(byte: u8) -> bool {
+        byte.is_ascii_alphabetic() || byte == b'_' || byte >= 0x80
+    }
+
+    let bytes = name.as_bytes();
+    let start = match bytes {
+        [b'-', b'-', ..] => 2,
+        [b'-', next, ..] if is_start(*next) => 2,
+        [first, ..] if is_start(*first) => 1,
+        _ => return false,
+    };
+    let mut index = start;
+    while index < bytes.len() {
+        let byte = bytes[index];
+        if !(is_start(byte) || byte.is_ascii_digit() || byte == b'-') {
+            return false;
+        }
+        index += 1;
+    }
+    true
+}
+
+/// Returns the given tag name, panicking if it's not valid.
+///
+/// Used in a const context, an invalid name fails the compilation.
+///
+/// ```rust
+/// const CUSTOM: &str = another_html_builder::name::tag_name("my-element");
+/// ```
+///
+/// ```compile_fail
+/// const CUSTOM: &str = another_html_builder::name::tag_name("my element");
+/// ```
+pub const fn tag_name(name: &str) -> &str {
+    assert!(is_valid_tag_name(name), "invalid tag name");
+    name
+}
+
+/// Returns the given attribute name, panicking if it's not valid.
+///
+/// Used in a const context, an invalid name fails the compilation.
+///
+/// ```rust
+/// const CUSTOM: &str = another_html_builder::name::attribute_name("data-id");
+/// ```
+///
+/// ```compile_fail
+/// const CUSTOM: &str = another_html_builder::name::attribute_name("data-id=\"a\"");
+/// ```
+pub const fn attribute_name(name: &str) -> &str {
+    assert!(is_valid_attribute_name(name), "invalid attribute name");
+    name
+}
+
+/// Validates a tag name literal at compile time.
+///
+/// ```rust
+/// let html = another_html_builder::Buffer::default()
+///     .node(another_html_builder::tag_name!("my-element"))
+///     .close()
+///     .into_inner();
+/// assert_eq!(html, "<my-element></my-element>");
+/// ```
+///
+/// ```compile_fail
+/// let html = another_html_builder::Buffer::default()
+///     .node(another_html_builder::tag_name!("div onclick=alert(1)"))
+///     .close()
+///     .into_inner();
+/// ```
+#[macro_export]
+macro_rules! tag_name {
+    ($name:expr) => {{
+        const NAME: &str = $crate::name::tag_name($name);
+        NAME
+    }};
+}
+
+/// Validates an attribute name literal at compile time.
+///
+/// ```rust
+/// let html = another_html_builder::Buffer::default()
+///     .node("div")
+///     .attr((another_html_builder::attribute_name!("data-id"), 42))
+///     .close()
+///     .into_inner();
+/// assert_eq!(html, "<div data-id=\"42\"></div>");
+/// ```
+///
+/// ```compile_fail
+/// let html = another_html_builder::Buffer::default()
+///     .node("div")
+///     .attr((another_html_builder::attribute_name!("a onclick"), 42))
+///     .close()
+///     .into_inner();
+/// ```
+#[macro_export]
+macro_rules! attribute_name {
+    ($name:expr) => {{
+        const NAME: &str = $crate::name::attribute_name($name);
+        NAME
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    #[test_case::test_case("div", true; "simple")]
+    #[test_case::test_case("H1", true; "uppercase with digit")]
+    #[test_case::test_case("my-element", true; "custom element")]
+    #[test_case::test_case("math:mi", true; "with namespace")]
+    #[test_case::test_case("élément", false; "starting with non ascii")]
+    #[test_case::test_case("", false; "empty")]
+    #[test_case::test_case("1div", false; "starting with digit")]
+    #[test_case::test_case("div onclick=alert(1)", false; "with injection")]
+    #[test_case::test_case("div>", false; "with bracket")]
+    #[test_case::test_case("di\0v", false; "with nul")]
+    fn tag_name(input: &str, expected: bool) {
+        assert_eq!(super::is_valid_tag_name(input), expected);
+    }
+
+    #[test_case::test_case("class", true; "simple")]
+    #[test_case::test_case("data-été", true; "with non ascii")]
+    #[test_case::test_case(":class", true; "starting with colon")]
+    #[test_case::test_case("", false; "empty")]
+    #[test_case::test_case("a b", false; "with space")]
+    #[test_case::test_case("a\tb", false; "with tab")]
+    #[test_case::test_case("a\"b", false; "with double quote")]
+    #[test_case::test_case("a'b", false; "with single quote")]
+    #[test_case::test_case("a/b", false; "with slash")]
+    #[test_case::test_case("a=b", false; "with equal")]
+    #[test_case::test_case("a\u{85}b", false; "with c1 control")]
+    fn attribute_name(input: &str, expected: bool) {
+        assert_eq!(super::is_valid_attribute_name(input), expected);
+    }
+
+    #[test_case::test_case("color", true; "simple")]
+    #[test_case::test_case("-moz-appearance", true; "with vendor prefix")]
+    #[test_case::test_case("--é2", true; "custom property")]
+    #[test_case::test_case("", false; "empty")]
+    #[test_case::test_case("-", false; "single dash")]
+    #[test_case::test_case("-1a", false; "dash and digit")]
+    #[test_case::test_case("a b", false; "with space")]
+    #[test_case::test_case("a:b", false; "with colon")]
+    #[test_case::test_case("a;b", false; "with semicolon")]
+    #[test_case::test_case("a/**/", false; "with comment")]
+    fn css_property(input: &str, expected: bool) {
+        assert_eq!(super::is_valid_css_property(input), expected);
+    }
+}
@@ -1,4 +1,7 @@
 //! Set of extension implementations allowing to write to [std::fmt::Write] or [std::io::Write].
+//!
+//! With the `tokio` and `futures` features, this also provides writers for the
+//! `AsyncWrite` traits of those crates, see [AsyncWriterExt].
 
 /// Abstraction layer allowing not only to write to [std::fmt::Write] but also to [std::io::Write].
 pub trait WriterExt {
@@ -8,6 +11,19 @@ pub trait WriterExt {
     fn write<E: std::fmt::Display>(&mut self, input: E) -> Result<(), Self::Error>;
     fn write_str(&mut self, input: &str) -> Result<(), Self::Error>;
     fn write_char(&mut self, input: char) -> Result<(), Self::Error>;
+
+    /// Flushes the underlying writer, when it's buffering what has been written.
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Handles an error of the [Buffer](crate::Buffer), located in the document.
+    ///
+    /// The error is returned by default, and [Latched] keeps it instead so that the
+    /// writing carries on.
+    fn latch(&mut self, error: crate::Error<Self::Error>) -> Result<(), crate::Error<Self::Error>> {
+        Err(error)
+    }
 }
 
 /// Wrapper for writer implementing [std::fmt::Write].
@@ -35,7 +51,16 @@ impl<W: std::io::Write> WriterExt for IoWriter<W> {
     type Error = std::io::Error;
 
     fn write<E: std::fmt::Display>(&mut self, input: E) -> std::io::Result<()> {
-        write!(self.0, "{input}")
+        let mut adapter = IoAdapter {
+            inner: &mut self.0,
+            error: None,
+        };
+        match std::fmt::Write::write_fmt(&mut adapter, format_args!("{input}")) {
+            Ok(()) => Ok(()),
+            Err(_) => Err(adapter.error.unwrap_or_else(|| {
+                std::io::Error::new(std::io::ErrorKind::InvalidData, "formatter error")
+            })),
+        }
     }
 
     fn write_str(&mut self, input: &str) -> std::io::Result<()> {
@@ -45,4 +70,288 @@ impl<W: std::io::Write> WriterExt for IoWriter<W> {
     fn write_char(&mut self, input: char) -> std::io::Result<()> {
         write!(self.0, "{input}")
     }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.0.flush()
+    }
+}
+
+/// Wrapper keeping the first error of the [Buffer](crate::Buffer) instead of returning it.
+///
+/// The errors of the underlying writer, as well as the content rejected by the buffer,
+/// like an invalid tag name, are kept with their location in the document. Once an error
+/// happened, everything written afterwards is ignored and the error is given back by
+/// [Latched::into_result]. This makes the infallible methods of the [Buffer](crate::Buffer)
+/// safe to use with writers that can fail, like a socket, see
+/// [Buffer::latched](crate::Buffer::latched).
+pub struct Latched<W: WriterExt> {
+    inner: W,
+    error: Option<crate::Error<W::Error>>,
+}
+
+impl<W: WriterExt> Latched<W> {
+    pub fn new(inner: W) -> Self {
+        Self { inner, error: None }
+    }
+
+    /// Returns the first error that happened, if any.
+    pub fn error(&self) -> Option<&crate::Error<W::Error>> {
+        self.error.as_ref()
+    }
+
+    /// Returns the underlying writer, or the first error that happened.
+    pub fn into_result(self) -> Result<W, crate::Error<W::Error>> {
+        match self.error {
+            Some(error) => Err(error),
+            None => Ok(self.inner),
+        }
+    }
+
+    fn forward<F: FnOnce(&mut W) -> Result<(), W::Error>>(
+        &mut self,
+        func: F,
+    ) -> Result<(), W::Error> {
+        match self.error {
+            Some(_) => Ok(()),
+            None => func(&mut self.inner),
+        }
+    }
+}
+
+/// The errors of the underlying writer are returned to the [Buffer](crate::Buffer),
+/// which gives them back located with [WriterExt::latch].
+impl<W: WriterExt> WriterExt for Latched<W> {
+    type Error = W::Error;
+
+    fn write<E: std::fmt::Display>(&mut self, input: E) -> Result<(), Self::Error> {
+        self.forward(|inner| inner.write(input))
+    }
+
+    fn write_str(&mut self, input: &str) -> Result<(), Self::Error> {
+        self.forward(|inner| inner.write_str(input))
+    }
+
+    fn write_char(&mut self, input: char) -> Result<(), Self::Error> {
+        self.forward(|inner| inner.write_char(input))
+    }
+
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        self.forward(|inner| inner.flush())
+    }
+
+    fn latch(&mut self, error: crate::Error<Self::Error>) -> Result<(), crate::Error<Self::Error>> {
+        if self.error.is_none() {
+            self.error = Some(error);
+        }
+        Ok(())
+    }
+}
+
+/// Adapter writing formatted values to a [std::io::Write] and keeping the io error.
+///
+/// Unlike [std::io::Write::write_fmt], an error coming from the formatted value is
+/// returned as an [std::io::ErrorKind::InvalidData] error instead of panicking.
+struct IoAdapter<'a, W> {
+    inner: &'a mut W,
+    error: Option<std::io::Error>,
+}
+
+impl<W: std::io::Write> std::fmt::Write for IoAdapter<'_, W> {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        self.inner.write_all(s.as_bytes()).map_err(|err| {
+            self.error = Some(err);
+            std::fmt::Error
+        })
+    }
+}
+
+/// Abstraction layer allowing to write to an asynchronous writer.
+///
+/// The [Buffer](crate::Buffer) methods are synchronous, so the asynchronous writers keep
+/// what is written in memory until [AsyncWriterExt::flush_async] sends it to the underlying
+/// writer. Flushing regularly, like after each section of a large page, keeps the memory
+/// usage low without blocking the executor. The synchronous [WriterExt::flush] doesn't
+/// do anything for those writers.
+///
+/// The content is removed from the pending content as soon as the underlying writer
+/// accepts it. When flushing fails or is cancelled, only what hasn't been written is kept,
+/// so flushing again resumes where it stopped without writing anything twice.
+///
+/// The returned future is [Send], so the rendering can be moved to another task,
+/// like with `tokio::spawn`. The underlying writers are required to be [Send] for that.
+pub trait AsyncWriterExt: WriterExt {
+    /// Sends the pending content to the underlying writer and flushes it.
+    fn flush_async(&mut self) -> impl std::future::Future<Output = Result<(), Self::Error>> + Send;
+}
+
+/// Writes the formatted value at the end of the pending content of an asynchronous writer.
+#[cfg(any(feature = "futures", feature = "tokio"))]
+fn write_pending<E: std::fmt::Display>(pending: &mut Vec<u8>, input: E) -> std::io::Result<()> {
+    struct Adapter<'a>(&'a mut Vec<u8>);
+
+    impl std::fmt::Write for Adapter<'_> {
+        fn write_str(&mut self, s: &str) -> std::fmt::Result {
+            self.0.extend_from_slice(s.as_bytes());
+            Ok(())
+        }
+    }
+
+    std::fmt::Write::write_fmt(&mut Adapter(pending), format_args!("{input}"))
+        .map_err(|_| std::io::Error::new(std::io::ErrorKind::InvalidData, "formatter error"))
+}
+
+/// Wrapper for writer implementing [tokio::io::AsyncWrite].
+///
+/// ```rust
+/// use another_html_builder::prelude::TokioWriter;
+/// use another_html_builder::Buffer;
+///
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// let buf = Buffer::from(TokioWriter::new(Vec::new()))
+///     .node("h1")
+///     .content(|buf| buf.text("Report"))
+///     .flush_async()
+///     .await
+///     .unwrap();
+/// let buf = buf
+///     .node("p")
+///     .content(|buf| buf.text("Hello"))
+///     .flush_async()
+///     .await
+///     .unwrap();
+/// assert_eq!(buf.into_inner(), b"<h1>Report</h1><p>Hello</p>");
+/// # });
+/// ```
+#[cfg(feature = "tokio")]
+pub struct TokioWriter<W> {
+    inner: W,
+    pending: Vec<u8>,
+}
+
+#[cfg(feature = "tokio")]
+impl<W> TokioWriter<W> {
+    pub fn new(inner: W) -> Self {
+        Self {
+            inner,
+            pending: Vec::new(),
+        }
+    }
+
+    /// Content written since the last call to [AsyncWriterExt::flush_async].
+    pub fn pending(&self) -> &[u8] {
+        &self.pending
+    }
+
+    /// Returns the underlying writer, dropping the pending content.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<W> WriterExt for TokioWriter<W> {
+    type Error = std::io::Error;
+
+    fn write<E: std::fmt::Display>(&mut self, input: E) -> std::io::Result<()> {
+        write_pending(&mut self.pending, input)
+    }
+
+    fn write_str(&mut self, input: &str) -> std::io::Result<()> {
+        self.pending.extend_from_slice(input.as_bytes());
+        Ok(())
+    }
+
+    fn write_char(&mut self, input: char) -> std::io::Result<()> {
+        self.write_str(input.encode_utf8(&mut [0; 4]))
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<W: tokio::io::AsyncWrite + Unpin + Send> AsyncWriterExt for TokioWriter<W> {
+    async fn flush_async(&mut self) -> std::io::Result<()> {
+        use tokio::io::AsyncWriteExt;
+
+        while !self.pending.is_empty() {
+            match self.inner.write(&self.pending).await? {
+                0 => return Err(std::io::ErrorKind::WriteZero.into()),
+                written => drop(self.pending.drain(..written)),
+            }
+        }
+        self.inner.flush().await
+    }
+}
+
+/// Wrapper for writer implementing [futures_util::AsyncWrite].
+///
+/// ```rust
+/// use another_html_builder::prelude::FuturesWriter;
+/// use another_html_builder::Buffer;
+///
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// let buf = Buffer::from(FuturesWriter::new(Vec::new()))
+///     .node("p")
+///     .content(|buf| buf.text("Hello"))
+///     .flush_async()
+///     .await
+///     .unwrap();
+/// assert_eq!(buf.into_inner(), b"<p>Hello</p>");
+/// # });
+/// ```
+#[cfg(feature = "futures")]
+pub struct FuturesWriter<W> {
+    inner: W,
+    pending: Vec<u8>,
+}
+
+#[cfg(feature = "futures")]
+impl<W> FuturesWriter<W> {
+    pub fn new(inner: W) -> Self {
+        Self {
+            inner,
+            pending: Vec::new(),
+        }
+    }
+
+    /// Content written since the last call to [AsyncWriterExt::flush_async].
+    pub fn pending(&self) -> &[u8] {
+        &self.pending
+    }
+
+    /// Returns the underlying writer, dropping the pending content.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+#[cfg(feature = "futures")]
+impl<W> WriterExt for FuturesWriter<W> {
+    type Error = std::io::Error;
+
+    fn write<E: std::fmt::Display>(&mut self, input: E) -> std::io::Result<()> {
+        write_pending(&mut self.pending, input)
+    }
+
+    fn write_str(&mut self, input: &str) -> std::io::Result<()> {
+        self.pending.extend_from_slice(input.as_bytes());
+        Ok(())
+    }
+
+    fn write_char(&mut self, input: char) -> std::io::Result<()> {
+        self.write_str(input.encode_utf8(&mut [0; 4]))
+    }
+}
+
+#[cfg(feature = "futures")]
+impl<W: futures_util::AsyncWrite + Unpin + Send> AsyncWriterExt for FuturesWriter<W> {
+    async fn flush_async(&mut self) -> std::io::Result<()> {
+        use futures_util::AsyncWriteExt;
+
+        while !self.pending.is_empty() {
+            match self.inner.write(&self.pending).await? {
+                0 => return Err(std::io::ErrorKind::WriteZero.into()),
+                written => drop(self.pending.drain(..written)),
+            }
+        }
+        self.inner.flush().await
+    }
 }
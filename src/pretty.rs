@@ -0,0 +1,156 @@
+//! Pretty printing related module. This contains the options to configure the
+//! indentation of the [Buffer](crate::Buffer) output.
+//!
+//! Line breaks and indentation are only added around block elements. Inline elements
+//! and whitespace sensitive elements (`pre`, `textarea`, `script` and `style`) are written
+//! as is, as well as everything they contain, so the rendering of the page doesn't change.
+
+/// List of the html elements that are part of the phrasing content.
+///
+/// See <https://html.spec.whatwg.org/multipage/dom.html#phrasing-content>.
+const INLINE_ELEMENTS: [&str; 50] = [
+    "a", "abbr", "audio", "b", "bdi", "bdo", "br", "button", "canvas", "cite", "code", "data",
+    "datalist", "del", "dfn", "em", "embed", "i", "iframe", "img", "input", "ins", "kbd", "label",
+    "map", "mark", "math", "meter", "object", "output", "picture", "progress", "q", "ruby", "s",
+    "samp", "select", "slot", "small", "span", "strong", "sub", "sup", "svg", "textarea", "time",
+    "u", "var", "video", "wbr",
+];
+
+/// List of the html elements in which whitespaces are meaningful.
+const WHITESPACE_SENSITIVE_ELEMENTS: [&str; 4] = ["pre", "script", "style", "textarea"];
+
+/// Checks if the given tag name is an inline html element.
+pub(crate) fn is_inline_element(name: &str) -> bool {
+    INLINE_ELEMENTS
+        .iter()
+        .any(|item| item.eq_ignore_ascii_case(name))
+}
+
+/// Checks if the content of the given tag name should be written as is.
+pub(crate) fn is_whitespace_sensitive_element(name: &str) -> bool {
+    WHITESPACE_SENSITIVE_ELEMENTS
+        .iter()
+        .any(|item| item.eq_ignore_ascii_case(name))
+}
+
+/// Character used to indent the lines.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum IndentStyle {
+    #[default]
+    Spaces,
+    Tabs,
+}
+
+/// Sequence used to break the lines.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Newline {
+    /// `\n`
+    #[default]
+    Lf,
+    /// `\r\n`
+    CrLf,
+}
+
+impl Newline {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Lf => "\n",
+            Self::CrLf => "\r\n",
+        }
+    }
+}
+
+/// Pretty printing options of the [Buffer](crate::Buffer).
+///
+/// By default, the lines are indented with 2 spaces and separated with `\n`.
+///
+/// ```rust
+/// use another_html_builder::pretty::{IndentStyle, Pretty};
+///
+/// let html = another_html_builder::Buffer::default()
+///     .pretty(Pretty {
+///         style: IndentStyle::Tabs,
+///         width: 1,
+///         ..Default::default()
+///     })
+///     .node("ul")
+///     .content(|buf| {
+///         buf.node("li").content(|buf| {
+///             buf.text("Hello ")
+///                 .node("b")
+///                 .content(|buf| buf.text("World"))
+///         })
+///     })
+///     .into_inner();
+/// assert_eq!(html, "<ul>\n\t<li>Hello <b>World</b></li>\n</ul>");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Pretty {
+    /// Character used to indent the lines.
+    pub style: IndentStyle,
+    /// Number of characters used for each level of indentation.
+    pub width: usize,
+    /// Sequence used to break the lines.
+    pub newline: Newline,
+}
+
+impl Default for Pretty {
+    fn default() -> Self {
+        Self {
+            style: IndentStyle::Spaces,
+            width: 2,
+            newline: Newline::Lf,
+        }
+    }
+}
+
+impl Pretty {
+    /// Creates a line break followed by the indentation for the given depth.
+    pub(crate) fn line_break(&self, depth: usize) -> LineBreak<'_> {
+        LineBreak {
+            options: self,
+            depth,
+        }
+    }
+}
+
+/// Wrapper writing a line break followed by the indentation.
+pub(crate) struct LineBreak<'a> {
+    options: &'a Pretty,
+    depth: usize,
+}
+
+impl std::fmt::Display for LineBreak<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.options.newline.as_str())?;
+        let indent = match self.options.style {
+            IndentStyle::Spaces => ' ',
+            IndentStyle::Tabs => '\t',
+        };
+        for _ in 0..(self.depth * self.options.width) {
+            std::fmt::Write::write_char(f, indent)?;
+        }
+        Ok(())
+    }
+}
+
+/// State of the layout, used to know where line breaks should be written.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct Layout {
+    /// Something has already been written in the document.
+    pub started: bool,
+    /// A block element has been written in the current element.
+    pub nested_block: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{IndentStyle, Newline, Pretty};
+
+    #[test_case::test_case(Pretty::default(), 0, "\n"; "without depth")]
+    #[test_case::test_case(Pretty::default(), 2, "\n    "; "with spaces")]
+    #[test_case::test_case(Pretty { style: IndentStyle::Tabs, width: 1, newline: Newline::CrLf }, 2, "\r\n\t\t"; "with tabs")]
+    fn line_break(options: Pretty, depth: usize, expected: &str) {
+        assert_eq!(options.line_break(depth).to_string(), expected);
+    }
+}
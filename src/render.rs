@@ -0,0 +1,242 @@
+//! Component related module. This contains the traits used to create reusable components.
+//!
+//! ```rust
+//! use another_html_builder::prelude::WriterExt;
+//! use another_html_builder::render::Render;
+//! use another_html_builder::{Body, Buffer};
+//!
+//! struct Item<'a>(&'a str);
+//!
+//! impl<W: WriterExt> Render<W> for Item<'_> {
+//!     fn render<'a>(&self, buf: Buffer<W, Body<'a>>) -> Buffer<W, Body<'a>> {
+//!         buf.node("li").content(|buf| buf.text(self.0))
+//!     }
+//! }
+//!
+//! let items = vec![Item("first"), Item("second")];
+//! let html = Buffer::default()
+//!     .node("ul")
+//!     .content(|buf| buf.render(&items))
+//!     .into_inner();
+//! assert_eq!(html, "<ul><li>first</li><li>second</li></ul>");
+//! ```
+
+use crate::prelude::WriterExt;
+use crate::{Body, Buffer, Error};
+
+/// Represents a component that can be written in a [Buffer].
+///
+/// This is implemented for `Option`, `Vec`, slices and tuples of components and for
+/// closures taking and returning a [Buffer] (see [from_fn]).
+pub trait Render<W: WriterExt> {
+    fn render<'a>(&self, buf: Buffer<W, Body<'a>>) -> Buffer<W, Body<'a>>;
+}
+
+/// Represents a component that can be written in a [Buffer] and can fail.
+///
+/// This is the fallible counterpart of [Render].
+pub trait TryRender<W: WriterExt> {
+    fn try_render<'a>(
+        &self,
+        buf: Buffer<W, Body<'a>>,
+    ) -> Result<Buffer<W, Body<'a>>, Error<W::Error>>;
+}
+
+/// Helps the compiler to infer the signature of a closure used as a component.
+///
+/// Closures taking and returning a [Buffer] implement [Render], but their signature
+/// can only be inferred when the closure is passed to a function expecting it.
+///
+/// ```rust
+/// use another_html_builder::render::from_fn;
+///
+/// let title = from_fn(|buf| buf.node("h1").content(|buf| buf.text("Hello")));
+/// let html = another_html_builder::Buffer::default()
+///     .render(&title)
+///     .render(&title)
+///     .into_inner();
+/// assert_eq!(html, "<h1>Hello</h1><h1>Hello</h1>");
+/// ```
+pub fn from_fn<W, F>(func: F) -> F
+where
+    W: WriterExt,
+    F: Fn(Buffer<W, Body>) -> Buffer<W, Body>,
+{
+    func
+}
+
+/// Helps the compiler to infer the signature of a closure used as a fallible component.
+///
+/// This is the fallible counterpart of [from_fn].
+pub fn try_from_fn<W, F>(func: F) -> F
+where
+    W: WriterExt,
+    F: Fn(Buffer<W, Body>) -> Result<Buffer<W, Body>, Error<W::Error>>,
+{
+    func
+}
+
+impl<W, F> Render<W> for F
+where
+    W: WriterExt,
+    F: Fn(Buffer<W, Body>) -> Buffer<W, Body>,
+{
+    fn render<'a>(&self, buf: Buffer<W, Body<'a>>) -> Buffer<W, Body<'a>> {
+        self(buf)
+    }
+}
+
+impl<W, F> TryRender<W> for F
+where
+    W: WriterExt,
+    F: Fn(Buffer<W, Body>) -> Result<Buffer<W, Body>, Error<W::Error>>,
+{
+    fn try_render<'a>(
+        &self,
+        buf: Buffer<W, Body<'a>>,
+    ) -> Result<Buffer<W, Body<'a>>, Error<W::Error>> {
+        self(buf)
+    }
+}
+
+impl<W: WriterExt, R: Render<W>> Render<W> for Option<R> {
+    fn render<'a>(&self, buf: Buffer<W, Body<'a>>) -> Buffer<W, Body<'a>> {
+        match self {
+            Some(inner) => inner.render(buf),
+            None => buf,
+        }
+    }
+}
+
+impl<W: WriterExt, R: TryRender<W>> TryRender<W> for Option<R> {
+    fn try_render<'a>(
+        &self,
+        buf: Buffer<W, Body<'a>>,
+    ) -> Result<Buffer<W, Body<'a>>, Error<W::Error>> {
+        match self {
+            Some(inner) => inner.try_render(buf),
+            None => Ok(buf),
+        }
+    }
+}
+
+impl<W: WriterExt, R: Render<W>> Render<W> for [R] {
+    fn render<'a>(&self, buf: Buffer<W, Body<'a>>) -> Buffer<W, Body<'a>> {
+        self.iter().fold(buf, |buf, item| item.render(buf))
+    }
+}
+
+impl<W: WriterExt, R: TryRender<W>> TryRender<W> for [R] {
+    fn try_render<'a>(
+        &self,
+        buf: Buffer<W, Body<'a>>,
+    ) -> Result<Buffer<W, Body<'a>>, Error<W::Error>> {
+        self.iter().try_fold(buf, |buf, item| item.try_render(buf))
+    }
+}
+
+impl<W: WriterExt, R: Render<W>> Render<W> for Vec<R> {
+    fn render<'a>(&self, buf: Buffer<W, Body<'a>>) -> Buffer<W, Body<'a>> {
+        self.as_slice().render(buf)
+    }
+}
+
+impl<W: WriterExt, R: TryRender<W>> TryRender<W> for Vec<R> {
+    fn try_render<'a>(
+        &self,
+        buf: Buffer<W, Body<'a>>,
+    ) -> Result<Buffer<W, Body<'a>>, Error<W::Error>> {
+        self.as_slice().try_render(buf)
+    }
+}
+
+macro_rules! render_tuple {
+    ($($name:ident),+) => {
+        impl<W: WriterExt, $($name: Render<W>),+> Render<W> for ($($name,)+) {
+            #[allow(non_snake_case)]
+            fn render<'a>(&self, buf: Buffer<W, Body<'a>>) -> Buffer<W, Body<'a>> {
+                let ($($name,)+) = self;
+                $(let buf = $name.render(buf);)+
+                buf
+            }
+        }
+
+        impl<W: WriterExt, $($name: TryRender<W>),+> TryRender<W> for ($($name,)+) {
+            #[allow(non_snake_case)]
+            fn try_render<'a>(&self, buf: Buffer<W, Body<'a>>) -> Result<Buffer<W, Body<'a>>, Error<W::Error>> {
+                let ($($name,)+) = self;
+                $(let buf = $name.try_render(buf)?;)+
+                Ok(buf)
+            }
+        }
+    };
+}
+
+render_tuple!(A);
+render_tuple!(A, B);
+render_tuple!(A, B, C);
+render_tuple!(A, B, C, D);
+render_tuple!(A, B, C, D, E);
+render_tuple!(A, B, C, D, E, F);
+render_tuple!(A, B, C, D, E, F, G);
+render_tuple!(A, B, C, D, E, F, G, H);
+
+#[cfg(test)]
+mod tests {
+    use super::{Render, TryRender};
+    use crate::prelude::WriterExt;
+    use crate::{Body, Buffer, Error};
+
+    struct Text(&'static str);
+
+    impl<W: WriterExt> Render<W> for Text {
+        fn render<'a>(&self, buf: Buffer<W, Body<'a>>) -> Buffer<W, Body<'a>> {
+            buf.node("p").content(|buf| buf.text(self.0))
+        }
+    }
+
+    impl<W: WriterExt> TryRender<W> for Text {
+        fn try_render<'a>(
+            &self,
+            buf: Buffer<W, Body<'a>>,
+        ) -> Result<Buffer<W, Body<'a>>, Error<W::Error>> {
+            buf.try_node("p")?.try_content(|buf| buf.try_text(self.0))
+        }
+    }
+
+    #[test]
+    fn should_render_collections() {
+        let html = Buffer::default()
+            .render(&Some(Text("a")))
+            .render(&None::<Text>)
+            .render(&vec![Text("b"), Text("c")])
+            .render(&[Text("d")][..])
+            .render(&(Text("e"), Some(Text("f"))))
+            .into_inner();
+        assert_eq!(html, "<p>a</p><p>b</p><p>c</p><p>d</p><p>e</p><p>f</p>");
+    }
+
+    #[test]
+    fn should_try_render_collections() {
+        let html = Buffer::default()
+            .try_render(&Some(Text("a")))
+            .and_then(|buf| buf.try_render(&vec![Text("b"), Text("c")]))
+            .and_then(|buf| buf.try_render(&(Text("d"), None::<Text>)))
+            .unwrap()
+            .into_inner();
+        assert_eq!(html, "<p>a</p><p>b</p><p>c</p><p>d</p>");
+    }
+
+    #[test]
+    fn should_render_closures() {
+        let name = "world";
+        let hello = super::from_fn(|buf| buf.text("hello "));
+        let world = super::try_from_fn(|buf| buf.try_text(name));
+        let html = Buffer::default()
+            .render(&(hello, Some(&hello)))
+            .try_render(&world)
+            .unwrap()
+            .into_inner();
+        assert_eq!(html, "hello hello world");
+    }
+}